@@ -9,8 +9,8 @@ use reth_db::{init_db, DatabaseEnv};
 use reth_node_builder::{NodeBuilder, WithLaunchContext};
 use reth_node_core::{
     args::{
-        utils::EthereumChainSpecParser, DatabaseArgs, DatadirArgs, DebugArgs, DevArgs, NetworkArgs,
-        PayloadBuilderArgs, PruningArgs, RpcServerArgs, TxPoolArgs,
+        utils::EthereumChainSpecParser, DatabaseArgs, DatadirArgs, DebugArgs, DevArgs, ExExArgs,
+        NetworkArgs, PayloadBuilderArgs, PruningArgs, RpcServerArgs, TxPoolArgs,
     },
     node_config::NodeConfig,
     version,
@@ -107,6 +107,10 @@ pub struct NodeCommand<
     #[command(flatten)]
     pub pruning: PruningArgs,
 
+    /// All `ExEx` related arguments with --exex prefix
+    #[command(flatten)]
+    pub exex: ExExArgs,
+
     /// Additional cli arguments
     #[command(flatten, next_help_heading = "Extension")]
     pub ext: Ext,
@@ -159,6 +163,7 @@ impl<
             db,
             dev,
             pruning,
+            exex,
             ext,
         } = self;
 
@@ -177,6 +182,7 @@ impl<
             db,
             dev,
             pruning,
+            exex,
         };
 
         // Register the prometheus recorder before creating the database,
@@ -1,12 +1,19 @@
-use std::fmt::Debug;
+use std::{fmt::Debug, path::PathBuf};
 
+use alloy_primitives::BlockNumber;
+use futures::{Stream, StreamExt};
 use reth_node_api::{FullNodeComponents, NodeTypes, NodeTypesWithEngine};
 use reth_node_core::node_config::NodeConfig;
-use reth_primitives::Head;
-use reth_tasks::TaskExecutor;
-use tokio::sync::mpsc::UnboundedSender;
+use reth_primitives::{BlockNumHash, Head, SealedBlock, SealedHeader};
+use reth_provider::CanonStateSubscriptions;
+use reth_tasks::{shutdown::Shutdown, TaskExecutor};
+use tokio::sync::{
+    mpsc::{error::SendError, UnboundedSender},
+    oneshot,
+};
+use tokio_util::sync::{CancellationToken, WaitForCancellationFutureOwned};
 
-use crate::{ExExEvent, ExExNotifications};
+use crate::{ExExEvent, ExExNotifications, ExExReplayError, ExExReplayRequest};
 
 /// Captures the context that an `ExEx` has access to.
 pub struct ExExContext<Node: FullNodeComponents> {
@@ -24,6 +31,12 @@ pub struct ExExContext<Node: FullNodeComponents> {
     /// Additionally, the exex can pre-emptively emit a `FinishedHeight` event to specify what
     /// blocks to receive notifications for.
     pub events: UnboundedSender<ExExEvent>,
+    /// Channel used to request the `ExEx` manager to re-deliver notifications. See
+    /// [`Self::request_replay_from`].
+    pub replay_requests: UnboundedSender<ExExReplayRequest>,
+    /// Signal cancelled when the `ExEx` is unregistered or terminated by the `ExEx` manager. See
+    /// [`Self::on_stop_signal`].
+    pub stop_signal: CancellationToken,
     /// Channel to receive [`ExExNotification`](crate::ExExNotification)s.
     ///
     /// # Important
@@ -48,6 +61,8 @@ where
             .field("config", &self.config)
             .field("reth_config", &self.reth_config)
             .field("events", &self.events)
+            .field("replay_requests", &self.replay_requests)
+            .field("stop_signal", &self.stop_signal)
             .field("notifications", &self.notifications)
             .field("components", &"...")
             .finish()
@@ -55,6 +70,21 @@ where
 }
 
 impl<Node: FullNodeComponents> ExExContext<Node> {
+    /// Retains up to `capacity` most recent blocks delivered by [`Self::notifications`],
+    /// accessible via [`Self::recent_blocks`].
+    pub fn retain_recent_blocks(&mut self, capacity: usize) {
+        self.notifications.set_recent_blocks_capacity(capacity);
+    }
+
+    /// Returns up to `n` most recent blocks delivered by [`Self::notifications`], ordered by block
+    /// number in ascending order.
+    ///
+    /// Blocks reverted by a reorg or a revert are never returned. No blocks are returned, unless
+    /// enabled with [`Self::retain_recent_blocks`].
+    pub fn recent_blocks(&self, n: usize) -> Vec<SealedBlock> {
+        self.notifications.recent_blocks().last(n)
+    }
+
     /// Returns the transaction pool of the node.
     pub fn pool(&self) -> &Node::Pool {
         self.components.pool()
@@ -75,6 +105,17 @@ impl<Node: FullNodeComponents> ExExContext<Node> {
         self.components.provider()
     }
 
+    /// Returns a stream of the canonical chain tip headers.
+    ///
+    /// Unlike the notifications, the stream doesn't carry the blocks and their execution outcomes,
+    /// so it's cheaper to consume for the `ExEx`'s that only need to track the canonical head. On
+    /// reorgs, the tip of the new canonical chain is yielded.
+    pub fn canonical_head_stream(&self) -> impl Stream<Item = SealedHeader> + Send + 'static {
+        self.provider()
+            .canonical_state_stream()
+            .map(|notification| notification.tip().header.clone())
+    }
+
     /// Returns the handle to the network
     pub fn network(&self) -> &Node::Network {
         self.components.network()
@@ -92,4 +133,58 @@ impl<Node: FullNodeComponents> ExExContext<Node> {
     pub fn task_executor(&self) -> &TaskExecutor {
         self.components.task_executor()
     }
+
+    /// Returns the path of the file [`Self::reth_config`] was loaded from.
+    ///
+    /// The node doesn't reload the config at runtime, so an `ExEx` that needs to react to the
+    /// config changes should watch the file itself.
+    pub fn reth_config_path(&self) -> PathBuf {
+        self.config.config.clone().unwrap_or_else(|| self.config.datadir().config())
+    }
+
+    /// Returns a future that resolves when the node starts shutting down.
+    ///
+    /// The future can be selected alongside the notifications stream, so that the `ExEx` can
+    /// flush its state, close its resources and return. The node waits for the `ExEx` to return
+    /// before exiting, bounded by the graceful shutdown timeout.
+    pub fn on_shutdown_signal(&self) -> Shutdown {
+        self.task_executor().on_shutdown_signal().clone()
+    }
+
+    /// Returns a future that resolves when the `ExEx` is unregistered or terminated by the `ExEx`
+    /// manager.
+    ///
+    /// The `ExEx` should emit its final [`ExExEvent::FinishedHeight`] event and return, which is
+    /// not treated as a crash by the node. The notifications stream ends after the `ExEx` is
+    /// removed from the manager.
+    pub fn on_stop_signal(&self) -> WaitForCancellationFutureOwned {
+        self.stop_signal.clone().cancelled_owned()
+    }
+
+    /// Sends an [`ExExEvent::FinishedHeight`] event with the given block to the node.
+    ///
+    /// Returns an error if the events channel was closed, i.e. the `ExEx` manager is gone.
+    pub fn notify_finished_height(&self, height: BlockNumHash) -> Result<(), SendError<ExExEvent>> {
+        self.events.send(ExExEvent::FinishedHeight(height))
+    }
+
+    /// Requests the `ExEx` manager to re-deliver the notifications starting from the given block
+    /// number.
+    ///
+    /// The first re-delivered notification is the one with the committed chain containing the
+    /// requested block, followed by all notifications after it. The finished height of the `ExEx`
+    /// is reset, so a new [`ExExEvent::FinishedHeight`] event should be emitted after the
+    /// replayed notifications are processed.
+    ///
+    /// The notifications are read from the WAL, so they can be replayed until the WAL is finalized
+    /// past the requested block. Returns [`ExExReplayError::NotAvailable`] with the earliest
+    /// available block otherwise. In this case, the `ExEx` should backfill the missing blocks from
+    /// the provider instead.
+    pub async fn request_replay_from(&self, from: BlockNumber) -> Result<(), ExExReplayError> {
+        let (tx, rx) = oneshot::channel();
+        self.replay_requests
+            .send(ExExReplayRequest { from, tx })
+            .map_err(|_| ExExReplayError::ManagerClosed)?;
+        rx.await.map_err(|_| ExExReplayError::ManagerClosed)?
+    }
 }
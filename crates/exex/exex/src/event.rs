@@ -1,4 +1,6 @@
+use alloy_primitives::BlockNumber;
 use reth_primitives::BlockNumHash;
+use tokio::sync::oneshot;
 
 /// Events emitted by an `ExEx`.
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -11,3 +13,36 @@ pub enum ExExEvent {
     /// On reorgs, it's possible for the height to go down.
     FinishedHeight(BlockNumHash),
 }
+
+/// Request from an `ExEx` to re-deliver the notifications starting from the given block number.
+///
+/// See [`ExExContext::request_replay_from`](crate::ExExContext::request_replay_from).
+#[derive(Debug)]
+pub struct ExExReplayRequest {
+    /// The block number to re-deliver the notifications from.
+    pub from: BlockNumber,
+    /// Channel to send the result of the request to.
+    pub tx: oneshot::Sender<Result<(), ExExReplayError>>,
+}
+
+/// Errors that can occur when requesting a replay of notifications from the `ExEx` manager.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, thiserror::Error)]
+pub enum ExExReplayError {
+    /// Notifications for the requested block are not present in the WAL anymore, e.g. because
+    /// the WAL was finalized past it.
+    ///
+    /// The `ExEx` should backfill the blocks before the earliest available block from the
+    /// provider.
+    #[error(
+        "notifications from block {requested} are not available, earliest available block is {earliest_available:?}"
+    )]
+    NotAvailable {
+        /// The requested block number.
+        requested: BlockNumber,
+        /// The earliest block number available in the WAL, if any.
+        earliest_available: Option<BlockNumber>,
+    },
+    /// The `ExEx` manager is not running.
+    #[error("ExEx manager is not running")]
+    ManagerClosed,
+}
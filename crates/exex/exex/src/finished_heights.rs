@@ -0,0 +1,85 @@
+use std::{
+    collections::BTreeMap,
+    fs::File,
+    path::{Path, PathBuf},
+};
+
+use alloy_eips::BlockNumHash;
+use reth_tracing::tracing::debug;
+
+/// Storage of the last [`ExExEvent::FinishedHeight`](crate::ExExEvent::FinishedHeight) emitted
+/// by each `ExEx`, keyed by the `ExEx` ID.
+///
+/// The storage is backed by a single MessagePack-encoded file, which is overwritten atomically on
+/// every write, so that the finished heights survive node restarts.
+#[derive(Debug, Clone)]
+pub struct FinishedHeightsStorage {
+    /// The path to the storage file.
+    path: PathBuf,
+}
+
+impl FinishedHeightsStorage {
+    /// Creates a new instance of [`FinishedHeightsStorage`] backed by the file at the given path,
+    /// and creates the parent directory if it doesn't exist.
+    pub fn new(path: impl AsRef<Path>) -> eyre::Result<Self> {
+        if let Some(parent) = path.as_ref().parent() {
+            reth_fs_util::create_dir_all(parent)?;
+        }
+
+        Ok(Self { path: path.as_ref().to_path_buf() })
+    }
+
+    /// Reads the finished heights from the storage.
+    ///
+    /// Returns an empty map if nothing was written to the storage yet.
+    pub fn read(&self) -> eyre::Result<BTreeMap<String, BlockNumHash>> {
+        if !self.path.exists() {
+            return Ok(BTreeMap::new())
+        }
+
+        let mut file = File::open(&self.path)?;
+        let finished_heights = rmp_serde::decode::from_read(&mut file).map_err(|err| {
+            eyre::eyre!("failed to decode finished heights from {:?}: {err:?}", self.path)
+        })?;
+
+        Ok(finished_heights)
+    }
+
+    /// Writes the finished heights to the storage, replacing the previous ones.
+    pub fn write(&self, finished_heights: &BTreeMap<String, BlockNumHash>) -> eyre::Result<()> {
+        debug!(target: "exex::finished_heights", ?finished_heights, "Writing finished heights");
+
+        reth_fs_util::atomic_write_file(&self.path, |file| {
+            rmp_serde::encode::write(file, finished_heights)
+        })?;
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::BTreeMap;
+
+    use alloy_eips::BlockNumHash;
+    use alloy_primitives::B256;
+
+    use super::FinishedHeightsStorage;
+
+    #[test]
+    fn test_finished_heights_storage() -> eyre::Result<()> {
+        let temp_dir = tempfile::tempdir()?;
+        let storage = FinishedHeightsStorage::new(temp_dir.path().join("exex/finished_heights"))?;
+
+        assert!(storage.read()?.is_empty());
+
+        let finished_heights = BTreeMap::from([
+            ("exex_1".to_string(), BlockNumHash::new(1, B256::random())),
+            ("exex_2".to_string(), BlockNumHash::new(2, B256::random())),
+        ]);
+        storage.write(&finished_heights)?;
+        assert_eq!(storage.read()?, finished_heights);
+
+        Ok(())
+    }
+}
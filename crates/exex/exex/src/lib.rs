@@ -43,12 +43,26 @@ pub use context::*;
 mod event;
 pub use event::*;
 
+mod finished_heights;
+pub use finished_heights::*;
+
 mod manager;
 pub use manager::*;
 
 mod notifications;
 pub use notifications::*;
 
+mod recent_blocks;
+pub use recent_blocks::*;
+
+#[cfg(unix)]
+mod status_socket;
+#[cfg(unix)]
+pub use status_socket::*;
+
+mod stream;
+pub use stream::*;
+
 mod wal;
 pub use wal::*;
 
@@ -1,6 +1,8 @@
 use crate::{
-    wal::Wal, ExExEvent, ExExNotification, ExExNotifications, FinishedExExHeight, WalHandle,
+    wal::Wal, ExExEvent, ExExNotification, ExExNotifications, ExExReplayError, ExExReplayRequest,
+    ExExState, ExExStatus, FinishedExExHeight, FinishedHeightsStorage, WalHandle,
 };
+use alloy_primitives::BlockNumber;
 use futures::StreamExt;
 use itertools::Itertools;
 use metrics::Gauge;
@@ -9,24 +11,29 @@ use reth_chainspec::Head;
 use reth_metrics::{metrics::Counter, Metrics};
 use reth_primitives::{BlockNumHash, SealedHeader};
 use reth_provider::HeaderProvider;
-use reth_tracing::tracing::debug;
+use reth_tracing::tracing::{debug, error, warn};
 use std::{
-    collections::VecDeque,
+    collections::{BTreeMap, VecDeque},
     fmt::Debug,
     future::{poll_fn, Future},
-    ops::Not,
+    ops::{Not, RangeInclusive},
     pin::Pin,
     sync::{
         atomic::{AtomicUsize, Ordering},
         Arc,
     },
     task::{ready, Context, Poll},
+    time::{Duration, Instant},
 };
-use tokio::sync::{
-    mpsc::{self, error::SendError, UnboundedReceiver, UnboundedSender},
-    watch,
+use tokio::{
+    sync::{
+        mpsc::{self, error::SendError, UnboundedReceiver, UnboundedSender},
+        oneshot, watch,
+    },
+    task::JoinHandle,
+    time::{Interval, MissedTickBehavior, Sleep},
 };
-use tokio_util::sync::{PollSendError, PollSender, ReusableBoxFuture};
+use tokio_util::sync::{CancellationToken, PollSendError, PollSender, ReusableBoxFuture};
 
 /// Default max size of the internal state notifications buffer.
 ///
@@ -34,6 +41,21 @@ use tokio_util::sync::{PollSendError, PollSender, ReusableBoxFuture};
 /// or 17 minutes of 1-second blocks.
 pub const DEFAULT_EXEX_MANAGER_CAPACITY: usize = 1024;
 
+/// Default time given to the `ExEx`'s to process the buffered notifications on shutdown.
+///
+/// See [`ExExManager::drain`].
+pub const DEFAULT_EXEX_MANAGER_DRAIN_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// Default time given to an `ExEx` to emit its final [`ExExEvent::FinishedHeight`] event or exit
+/// after it's signaled to stop on unregistration.
+///
+/// See [`ExExManagerHandle::unregister_exex`].
+pub const DEFAULT_EXEX_UNREGISTER_TIMEOUT: Duration = Duration::from_secs(5);
+
+/// The max number of notifications read from the WAL ahead of their delivery to an `ExEx` that
+/// replays them, so that a long replay doesn't hold the whole WAL in memory.
+const WAL_REPLAY_READ_AHEAD: usize = 16;
+
 /// Metrics for an `ExEx`.
 #[derive(Metrics)]
 #[metrics(scope = "exex")]
@@ -42,6 +64,162 @@ struct ExExMetrics {
     notifications_sent_total: Counter,
     /// The total number of events an `ExEx` has sent to the manager.
     events_sent_total: Counter,
+    /// The number of notifications in the manager's buffer not yet delivered to an `ExEx`.
+    buffered_notifications: Gauge,
+    /// The number of blocks between the node head and the finished height of an `ExEx`.
+    blocks_behind: Gauge,
+    /// The total number of notifications dropped for an `ExEx` due to
+    /// [`ExExOverflowPolicy::DropOldest`].
+    notifications_dropped_total: Counter,
+}
+
+/// The policy applied when an `ExEx` has more buffered notifications than allowed by
+/// [`ExExHandle::with_max_buffered_notifications`].
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum ExExOverflowPolicy {
+    /// Stop accepting new notifications into the manager until the `ExEx` catches up, applying
+    /// backpressure to the node.
+    ///
+    /// The capacity reported by [`ExExManagerHandle::capacity`] is zero while the `ExEx` is at its
+    /// limit.
+    #[default]
+    Block,
+    /// Skip the oldest notifications not yet delivered to the `ExEx`, so that it receives at most
+    /// the configured number of latest notifications.
+    ///
+    /// The `ExEx` will miss the skipped notifications, and should backfill them from the provider
+    /// if needed.
+    DropOldest,
+    /// Terminate the `ExEx` by removing it from the manager, which cancels its
+    /// [`stop signal`](ExExHandle::stop_signal) and closes its notifications channel. The `ExEx`
+    /// is reported with the [`ExExState::Terminated`] status.
+    Error,
+}
+
+/// The action taken when an `ExEx` made no progress for the timeout set with
+/// [`ExExHandle::with_watchdog`].
+///
+/// An `ExEx` makes no progress if it has notifications to process, but doesn't emit a
+/// [`ExExEvent::FinishedHeight`] event.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum ExExWatchdogAction {
+    /// Log a warning on every watchdog interval the `ExEx` makes no progress.
+    #[default]
+    Warn,
+    /// Terminate the `ExEx` by removing it from the manager, which cancels its
+    /// [`stop signal`](ExExHandle::stop_signal) and closes its notifications channel. The `ExEx`
+    /// is reported with the [`ExExState::Terminated`] status.
+    Terminate,
+}
+
+/// Notifications replayed from the WAL to an `ExEx`.
+///
+/// The notifications are read in a blocking task, started on the first attempt to deliver them,
+/// at most [`WAL_REPLAY_READ_AHEAD`] notifications ahead of their delivery.
+#[derive(Debug)]
+struct ExExReplay {
+    /// The file IDs of the notifications in the WAL to replay.
+    file_ids: RangeInclusive<u32>,
+    /// Channel to receive the notifications read from the WAL, once the reading started.
+    notifications: Option<mpsc::Receiver<eyre::Result<ExExNotification>>>,
+    /// The next notification to deliver, if it was received but not delivered yet.
+    next: Option<ExExNotification>,
+}
+
+impl ExExReplay {
+    /// Creates a new replay of the notifications with the given file IDs.
+    const fn new(file_ids: RangeInclusive<u32>) -> Self {
+        Self { file_ids, notifications: None, next: None }
+    }
+
+    /// Polls the next notification to deliver, starting to read the notifications from the WAL if
+    /// not started yet.
+    ///
+    /// Returns `None` when all notifications were received.
+    fn poll_next(
+        &mut self,
+        cx: &mut Context<'_>,
+        wal: &Wal,
+    ) -> Poll<Option<eyre::Result<ExExNotification>>> {
+        if let Some(notification) = self.next.take() {
+            return Poll::Ready(Some(Ok(notification)))
+        }
+
+        self.notifications
+            .get_or_insert_with(|| {
+                wal.read_notifications(self.file_ids.clone(), WAL_REPLAY_READ_AHEAD)
+            })
+            .poll_recv(cx)
+    }
+}
+
+/// Watchdog supervising the progress of an `ExEx`.
+#[derive(Debug)]
+struct ExExWatchdog {
+    /// The duration without progress after which the action is taken.
+    timeout: Duration,
+    /// The action taken when the `ExEx` made no progress for the timeout.
+    action: ExExWatchdogAction,
+    /// The interval at which the progress is checked. Created lazily on the first check, because
+    /// it requires a Tokio runtime.
+    interval: Option<Interval>,
+    /// The last time the `ExEx` made progress or had nothing to process.
+    last_progress: Instant,
+}
+
+/// Rate limit of the blocks delivered to an `ExEx`.
+#[derive(Debug)]
+struct ExExRateLimit {
+    /// The max number of blocks delivered per period.
+    max_blocks: u64,
+    /// The period after which the number of delivered blocks is reset.
+    period: Duration,
+    /// The interval at which the number of delivered blocks is reset. Created lazily on the first
+    /// delivery, because it requires a Tokio runtime.
+    interval: Option<Interval>,
+    /// The number of blocks delivered in the current period.
+    delivered_blocks: u64,
+}
+
+impl ExExRateLimit {
+    /// Checks if the given number of blocks can be delivered in the current period, registering
+    /// the waker for the next period if not.
+    ///
+    /// A chain with more blocks than the max is delivered at the start of a period, so that it
+    /// doesn't block the `ExEx` forever. Notifications without committed blocks are always
+    /// delivered.
+    fn poll_ready(&mut self, cx: &mut Context<'_>, blocks: u64) -> Poll<()> {
+        if blocks == 0 {
+            return Poll::Ready(())
+        }
+
+        let period = self.period;
+        let interval = self.interval.get_or_insert_with(|| {
+            let mut interval = tokio::time::interval(period);
+            interval.set_missed_tick_behavior(MissedTickBehavior::Delay);
+            interval
+        });
+        while interval.poll_tick(cx).is_ready() {
+            self.delivered_blocks = 0;
+        }
+
+        if self.delivered_blocks > 0 && self.delivered_blocks + blocks > self.max_blocks {
+            return Poll::Pending
+        }
+        Poll::Ready(())
+    }
+}
+
+/// The lag of an `ExEx` behind the node.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct ExExLag {
+    /// The number of notifications in the manager's buffer not yet delivered to the `ExEx`.
+    pub buffered_notifications: usize,
+    /// The number of blocks between the node head and the finished height of the `ExEx`.
+    ///
+    /// If this is `None`, either the `ExEx` has not emitted a `FinishedHeight` event, or the
+    /// manager has not received any notifications yet.
+    pub blocks_behind: Option<u64>,
 }
 
 /// A handle to an `ExEx` used by the [`ExExManager`] to communicate with `ExEx`'s.
@@ -59,12 +237,33 @@ pub struct ExExHandle {
     sender: PollSender<ExExNotification>,
     /// Channel to receive [`ExExEvent`]s from the `ExEx`.
     receiver: UnboundedReceiver<ExExEvent>,
+    /// Channel to send [`ExExReplayRequest`]s to the manager, given to the `ExEx`.
+    replay_tx: UnboundedSender<ExExReplayRequest>,
+    /// Channel to receive [`ExExReplayRequest`]s from the `ExEx`.
+    replay_rx: UnboundedReceiver<ExExReplayRequest>,
+    /// The notifications from the WAL to be re-delivered to the `ExEx` before the buffered ones,
+    /// as requested by the last [`ExExReplayRequest`].
+    replay: Option<ExExReplay>,
     /// The ID of the next notification to send to this `ExEx`.
     next_notification_id: usize,
     /// The finished block of the `ExEx`.
     ///
     /// If this is `None`, the `ExEx` has not emitted a `FinishedHeight` event.
     finished_height: Option<BlockNumHash>,
+    /// The max number of buffered notifications not yet delivered to this `ExEx`.
+    ///
+    /// If this is `None`, the number is only bounded by the capacity of the manager's buffer.
+    max_buffered_notifications: Option<usize>,
+    /// The policy applied when the number of buffered notifications exceeds
+    /// [`Self::max_buffered_notifications`].
+    overflow_policy: ExExOverflowPolicy,
+    /// The watchdog supervising the progress of the `ExEx`, if any.
+    watchdog: Option<ExExWatchdog>,
+    /// The rate limit of the blocks delivered to the `ExEx`, if any.
+    rate_limit: Option<ExExRateLimit>,
+    /// The signal cancelled when the `ExEx` is unregistered or terminated by the manager, given to
+    /// the `ExEx`.
+    stop_signal: CancellationToken,
 }
 
 impl ExExHandle {
@@ -81,6 +280,7 @@ impl ExExHandle {
     ) -> (Self, UnboundedSender<ExExEvent>, ExExNotifications<P, E>) {
         let (notification_tx, notification_rx) = mpsc::channel(1);
         let (event_tx, event_rx) = mpsc::unbounded_channel();
+        let (replay_tx, replay_rx) = mpsc::unbounded_channel();
         let notifications =
             ExExNotifications::new(node_head, provider, executor, notification_rx, wal_handle);
 
@@ -90,14 +290,220 @@ impl ExExHandle {
                 metrics: ExExMetrics::new_with_labels(&[("exex", id)]),
                 sender: PollSender::new(notification_tx),
                 receiver: event_rx,
+                replay_tx,
+                replay_rx,
+                replay: None,
                 next_notification_id: 0,
                 finished_height: None,
+                max_buffered_notifications: None,
+                overflow_policy: ExExOverflowPolicy::default(),
+                watchdog: None,
+                rate_limit: None,
+                stop_signal: CancellationToken::new(),
             },
             event_tx,
             notifications,
         )
     }
 
+    /// Returns a [`UnboundedSender`] for [`ExExReplayRequest`]s that should be given to the
+    /// `ExEx`, alongside the channels returned by [`Self::new`].
+    pub fn replay_requests(&self) -> UnboundedSender<ExExReplayRequest> {
+        self.replay_tx.clone()
+    }
+
+    /// Returns the [`CancellationToken`] cancelled when the `ExEx` is unregistered or terminated
+    /// by the manager, that should be given to the `ExEx`.
+    ///
+    /// The `ExEx` should stop when the token is cancelled, and an `ExEx` returning after it should
+    /// not be treated as a crash.
+    pub fn stop_signal(&self) -> CancellationToken {
+        self.stop_signal.clone()
+    }
+
+    /// Sets the max number of buffered notifications not yet delivered to this `ExEx`, and the
+    /// policy applied when this number is exceeded.
+    pub const fn with_max_buffered_notifications(
+        mut self,
+        max_buffered_notifications: usize,
+        overflow_policy: ExExOverflowPolicy,
+    ) -> Self {
+        self.max_buffered_notifications = Some(max_buffered_notifications);
+        self.overflow_policy = overflow_policy;
+        self
+    }
+
+    /// Supervises the progress of this `ExEx`, taking the given action if it has notifications to
+    /// process, but doesn't emit a [`ExExEvent::FinishedHeight`] event for the given timeout.
+    ///
+    /// The progress is checked every `timeout`, so the action is taken after at most twice the
+    /// timeout.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `timeout` is zero.
+    pub fn with_watchdog(mut self, timeout: Duration, action: ExExWatchdogAction) -> Self {
+        assert!(!timeout.is_zero(), "ExEx watchdog timeout must be non-zero");
+        self.watchdog =
+            Some(ExExWatchdog { timeout, action, interval: None, last_progress: Instant::now() });
+        self
+    }
+
+    /// Limits the notifications delivered to this `ExEx` to at most `max_blocks` committed blocks
+    /// per `period`, e.g. to keep an expensive `ExEx` from starving the other tasks of the node
+    /// during a backfill.
+    ///
+    /// The notifications that are not delivered yet stay buffered in the manager, and count
+    /// towards its capacity and [`Self::with_max_buffered_notifications`]. Notifications that
+    /// only revert blocks are not limited.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `max_blocks` or `period` is zero.
+    pub fn with_rate_limit(mut self, max_blocks: u64, period: Duration) -> Self {
+        assert!(max_blocks > 0, "ExEx rate limit max blocks must be non-zero");
+        assert!(!period.is_zero(), "ExEx rate limit period must be non-zero");
+        self.rate_limit =
+            Some(ExExRateLimit { max_blocks, period, interval: None, delivered_blocks: 0 });
+        self
+    }
+
+    /// Returns `true` if the `ExEx` has notifications to process, i.e. there are replayed or
+    /// buffered notifications not yet delivered to it, or it hasn't emitted a
+    /// [`ExExEvent::FinishedHeight`] event for the node head yet.
+    fn has_unprocessed_notifications(&self, next_id: usize, head: Option<BlockNumber>) -> bool {
+        self.replay.is_some() ||
+            self.next_notification_id < next_id ||
+            head.is_some_and(|head| {
+                self.finished_height.map_or(true, |height| height.number < head)
+            })
+    }
+
+    /// Returns the number of buffered notifications not yet delivered to the `ExEx`, given the
+    /// next notification ID to be assigned by the manager.
+    const fn buffered_notifications(&self, next_id: usize) -> usize {
+        next_id.saturating_sub(self.next_notification_id)
+    }
+
+    /// Returns `true` if the `ExEx` reached its max number of buffered notifications and has the
+    /// [`ExExOverflowPolicy::Block`] policy.
+    fn is_blocking(&self, next_id: usize) -> bool {
+        self.overflow_policy == ExExOverflowPolicy::Block &&
+            self.max_buffered_notifications
+                .is_some_and(|max| self.buffered_notifications(next_id) >= max)
+    }
+
+    /// Takes into account the [`ExExEvent::FinishedHeight`] events already sent by the `ExEx`,
+    /// without waiting for new ones.
+    fn receive_queued_events(&mut self) {
+        while let Ok(ExExEvent::FinishedHeight(height)) = self.receiver.try_recv() {
+            self.finished_height = Some(height);
+        }
+    }
+
+    /// Returns the status of the `ExEx`.
+    fn status(&self) -> ExExStatus {
+        ExExStatus {
+            id: self.id.clone(),
+            finished_height: self.finished_height,
+            state: if self.sender.get_ref().map_or(true, |sender| sender.is_closed()) {
+                ExExState::Exited
+            } else {
+                ExExState::Running
+            },
+        }
+    }
+
+    /// Returns the lag of the `ExEx` given the next notification ID to be assigned by the manager
+    /// and the node head, and updates the metrics accordingly.
+    fn update_lag(&self, next_id: usize, head: Option<BlockNumber>) -> ExExLag {
+        let lag = ExExLag {
+            buffered_notifications: self.buffered_notifications(next_id),
+            blocks_behind: head
+                .zip(self.finished_height)
+                .map(|(head, finished_height)| head.saturating_sub(finished_height.number)),
+        };
+
+        self.metrics.buffered_notifications.set(lag.buffered_notifications as f64);
+        if let Some(blocks_behind) = lag.blocks_behind {
+            self.metrics.blocks_behind.set(blocks_behind as f64);
+        }
+
+        lag
+    }
+
+    /// Queues the notifications in the WAL starting from the first one with the committed chain
+    /// containing the given block number, so that they are delivered again before the buffered
+    /// notifications. The notifications are read from the WAL in the background while they are
+    /// delivered.
+    ///
+    /// All notifications received by the manager are committed to the WAL before being buffered,
+    /// so the buffered notifications not yet delivered to the `ExEx` are skipped, given the next
+    /// notification ID to be assigned by the manager. Resets the finished height of the `ExEx`,
+    /// because the replayed notifications would be skipped otherwise.
+    ///
+    /// Returns an error with the earliest available block number if there's no such notification
+    /// in the WAL.
+    fn replay_from(
+        &mut self,
+        wal: &Wal,
+        next_id: usize,
+        from: BlockNumber,
+    ) -> Result<(), ExExReplayError> {
+        let Some(file_ids) = wal.file_ids_from_committed_block(from) else {
+            return Err(ExExReplayError::NotAvailable {
+                requested: from,
+                earliest_available: wal.lowest_committed_block_number(),
+            })
+        };
+
+        debug!(
+            target: "exex::manager",
+            exex_id = %self.id,
+            %from,
+            ?file_ids,
+            "Replaying notifications"
+        );
+        self.replay = Some(ExExReplay::new(file_ids));
+        self.next_notification_id = self.next_notification_id.max(next_id);
+        self.finished_height = None;
+
+        Ok(())
+    }
+
+    /// Sends the next replayed notification, if any, removing it from the replay when it's
+    /// delivered.
+    ///
+    /// Returns `None` if there are no replayed notifications left, or the replay failed to read
+    /// the next one from the WAL.
+    fn send_replayed(
+        &mut self,
+        cx: &mut Context<'_>,
+        wal: &Wal,
+    ) -> Option<Poll<Result<(), PollSendError<ExExNotification>>>> {
+        let notification = match self.replay.as_mut()?.poll_next(cx, wal) {
+            Poll::Ready(Some(Ok(notification))) => notification,
+            Poll::Ready(Some(Err(err))) => {
+                error!(target: "exex::manager", exex_id = %self.id, %err, "Failed to read replayed notification from WAL, stopping the replay");
+                self.replay = None;
+                return None
+            }
+            Poll::Ready(None) => {
+                self.replay = None;
+                return None
+            }
+            Poll::Pending => return Some(Poll::Pending),
+        };
+
+        let poll = self.deliver(cx, &notification);
+        if !matches!(poll, Poll::Ready(Ok(()))) {
+            if let Some(replay) = &mut self.replay {
+                replay.next = Some(notification);
+            }
+        }
+        Some(poll)
+    }
+
     /// Reserves a slot in the `PollSender` channel and sends the notification if the slot was
     /// successfully reserved.
     ///
@@ -135,10 +541,36 @@ impl ExExHandle {
             }
         }
 
+        let poll = self.deliver(cx, notification);
+        if matches!(poll, Poll::Ready(Ok(()))) {
+            self.next_notification_id = notification_id + 1;
+        }
+        poll
+    }
+
+    /// Reserves a slot in the `PollSender` channel and sends the notification if the slot was
+    /// successfully reserved, respecting the rate limit of the `ExEx`.
+    fn deliver(
+        &mut self,
+        cx: &mut Context<'_>,
+        notification: &ExExNotification,
+    ) -> Poll<Result<(), PollSendError<ExExNotification>>> {
+        let committed_blocks = notification.committed_chain().map_or(0, |chain| chain.len() as u64);
+        if let Some(rate_limit) = &mut self.rate_limit {
+            if rate_limit.poll_ready(cx, committed_blocks).is_pending() {
+                debug!(
+                    target: "exex::manager",
+                    exex_id = %self.id,
+                    "Rate limit reached, delaying notification"
+                );
+                return Poll::Pending
+            }
+        }
+
         debug!(
             target: "exex::manager",
             exex_id = %self.id,
-            %notification_id,
+            committed_tip = ?notification.committed_chain().map(|chain| chain.tip().number),
             "Reserving slot for notification"
         );
         match self.sender.poll_reserve(cx) {
@@ -146,16 +578,13 @@ impl ExExHandle {
             other => return other,
         }
 
-        debug!(
-            target: "exex::manager",
-            exex_id = %self.id,
-            %notification_id,
-            "Sending notification"
-        );
+        debug!(target: "exex::manager", exex_id = %self.id, "Sending notification");
         match self.sender.send_item(notification.clone()) {
             Ok(()) => {
-                self.next_notification_id = notification_id + 1;
                 self.metrics.notifications_sent_total.increment(1);
+                if let Some(rate_limit) = &mut self.rate_limit {
+                    rate_limit.delivered_blocks += committed_blocks;
+                }
                 Poll::Ready(Ok(()))
             }
             Err(err) => Poll::Ready(Err(err)),
@@ -163,6 +592,61 @@ impl ExExHandle {
     }
 }
 
+/// Errors that can occur when registering or unregistering an `ExEx` with a running
+/// [`ExExManager`].
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+pub enum ExExRegistrationError {
+    /// An `ExEx` with the same ID is already registered.
+    #[error("ExEx with ID {0} is already registered")]
+    DuplicateId(String),
+    /// No `ExEx` with the given ID is registered.
+    #[error("ExEx with ID {0} is not registered")]
+    NotFound(String),
+    /// The `ExEx` manager is not running.
+    #[error("ExEx manager is not running")]
+    ManagerClosed,
+}
+
+/// A command sent to the [`ExExManager`] through an [`ExExManagerHandle`].
+#[derive(Debug)]
+enum ExExManagerCommand {
+    /// Register a new `ExEx`. See [`ExExManager::register_exex`].
+    Register {
+        /// The handle of the `ExEx` to register.
+        exex: ExExHandle,
+        /// The channel to send the result of the registration to.
+        tx: oneshot::Sender<Result<(), ExExRegistrationError>>,
+    },
+    /// Unregister a running `ExEx`. See [`ExExManagerHandle::unregister_exex`].
+    Unregister {
+        /// The ID of the `ExEx` to unregister.
+        id: String,
+        /// The time to wait for the final event of the `ExEx`.
+        timeout: Duration,
+        /// The channel to send the final status of the `ExEx` to.
+        tx: oneshot::Sender<Result<ExExStatus, ExExRegistrationError>>,
+    },
+    /// Report that an `ExEx` returned an error. See [`ExExManager::report_exex_error`].
+    ReportError {
+        /// The ID of the `ExEx` that returned the error.
+        id: String,
+        /// The error message.
+        error: String,
+    },
+}
+
+/// An `ExEx` signaled to stop on unregistration, waiting for its final
+/// [`ExExEvent::FinishedHeight`] event.
+#[derive(Debug)]
+struct UnregisteringExEx {
+    /// The handle of the `ExEx`, already removed from the manager.
+    exex: ExExHandle,
+    /// The deadline after which the `ExEx` is unregistered without its final event.
+    deadline: Pin<Box<Sleep>>,
+    /// The channel to send the final status of the `ExEx` to.
+    tx: oneshot::Sender<Result<ExExStatus, ExExRegistrationError>>,
+}
+
 /// Metrics for the `ExEx` manager.
 #[derive(Metrics)]
 #[metrics(scope = "exex.manager")]
@@ -198,11 +682,15 @@ pub struct ExExManager<P> {
 
     /// [`ExExNotification`] channel from the [`ExExManagerHandle`]s.
     handle_rx: UnboundedReceiver<ExExNotification>,
+    /// [`ExExManagerCommand`] channel from the [`ExExManagerHandle`]s.
+    command_rx: UnboundedReceiver<ExExManagerCommand>,
 
     /// The minimum notification ID currently present in the buffer.
     min_id: usize,
     /// Monotonically increasing ID for [`ExExNotification`]s.
     next_id: usize,
+    /// The latest block number of the node, according to the received notifications.
+    head: watch::Sender<Option<BlockNumber>>,
     /// Internal buffer of [`ExExNotification`]s.
     ///
     /// The first element of the tuple is a monotonically increasing ID unique to the notification
@@ -220,11 +708,34 @@ pub struct ExExManager<P> {
 
     /// The finished height of all `ExEx`'s.
     finished_height: watch::Sender<FinishedExExHeight>,
+    /// The lag of each `ExEx` behind the node, keyed by the `ExEx` ID.
+    lags: watch::Sender<BTreeMap<String, ExExLag>>,
+    /// The statuses of all `ExEx`'s.
+    statuses: watch::Sender<Vec<ExExStatus>>,
+    /// The statuses of `ExEx`'s terminated by the manager, or removed from it after they exited.
+    terminated_exexs: Vec<ExExStatus>,
+    /// The `ExEx`'s being unregistered through [`ExExManagerHandle::unregister_exex`].
+    unregistering_exexs: Vec<UnregisteringExEx>,
+    /// Whether the `ExEx`'s that closed their notifications channel are removed instead of
+    /// failing the manager. See [`Self::with_isolated_failures`].
+    isolate_failures: bool,
+    /// Whether the manager is draining on shutdown. See [`Self::drain`].
+    draining: bool,
 
     /// Write-Ahead Log for the [`ExExNotification`]s.
     wal: Wal,
     /// A stream of finalized headers.
     finalized_header_stream: ForkChoiceStream<SealedHeader>,
+    /// Storage to persist the finished heights of `ExEx`'s across restarts.
+    finished_heights_storage: Option<FinishedHeightsStorage>,
+    /// The finished heights to persist in the storage, including the ones of `ExEx`'s that are not
+    /// registered in the manager anymore, or not yet.
+    stored_finished_heights: BTreeMap<String, BlockNumHash>,
+    /// Whether [`Self::stored_finished_heights`] changed since the last write to the storage was
+    /// started.
+    stored_finished_heights_changed: bool,
+    /// The blocking task writing the finished heights to the storage, if any.
+    finished_heights_write: Option<JoinHandle<()>>,
 
     /// A handle to the `ExEx` manager.
     handle: ExExManagerHandle,
@@ -250,6 +761,7 @@ impl<P> ExExManager<P> {
         let num_exexs = handles.len();
 
         let (handle_tx, handle_rx) = mpsc::unbounded_channel();
+        let (command_tx, command_rx) = mpsc::unbounded_channel();
         let (is_ready_tx, is_ready_rx) = watch::channel(true);
         let (finished_height_tx, finished_height_rx) = watch::channel(if num_exexs == 0 {
             FinishedExExHeight::NoExExs
@@ -257,6 +769,11 @@ impl<P> ExExManager<P> {
             FinishedExExHeight::NotReady
         });
 
+        let (head_tx, head_rx) = watch::channel(None);
+        let (lags_tx, lags_rx) = watch::channel(BTreeMap::new());
+        let (statuses_tx, statuses_rx) =
+            watch::channel(handles.iter().map(ExExHandle::status).collect());
+
         let current_capacity = Arc::new(AtomicUsize::new(max_capacity));
 
         let metrics = ExExManagerMetrics::default();
@@ -269,166 +786,681 @@ impl<P> ExExManager<P> {
             exex_handles: handles,
 
             handle_rx,
+            command_rx,
 
             min_id: 0,
             next_id: 0,
+            head: head_tx,
             buffer: VecDeque::with_capacity(max_capacity),
             max_capacity,
             current_capacity: Arc::clone(&current_capacity),
 
             is_ready: is_ready_tx,
             finished_height: finished_height_tx,
+            lags: lags_tx,
+            statuses: statuses_tx,
+            terminated_exexs: Vec::new(),
+            unregistering_exexs: Vec::new(),
+            isolate_failures: false,
+            draining: false,
 
             wal,
             finalized_header_stream,
+            finished_heights_storage: None,
+            stored_finished_heights: BTreeMap::new(),
+            stored_finished_heights_changed: false,
+            finished_heights_write: None,
 
             handle: ExExManagerHandle {
                 exex_tx: handle_tx,
-                num_exexs,
+                command_tx,
+                num_exexs: Arc::new(AtomicUsize::new(num_exexs)),
                 is_ready_receiver: is_ready_rx.clone(),
                 is_ready: ReusableBoxFuture::new(make_wait_future(is_ready_rx)),
                 current_capacity,
                 finished_height: finished_height_rx,
+                head: head_rx,
+                lags: lags_rx,
+                statuses: statuses_rx,
             },
             metrics,
         }
     }
 
-    /// Returns the handle to the manager.
-    pub fn handle(&self) -> ExExManagerHandle {
-        self.handle.clone()
+    /// Keeps the manager running when an `ExEx` closes its notifications channel, e.g. because it
+    /// returned an error, by removing the `ExEx` and reporting it in its status.
+    ///
+    /// By default, an `ExEx` closing its notifications channel while the manager is running, i.e.
+    /// not on [`Self::drain`] or after it was unregistered or terminated, is irrecoverable and the
+    /// manager returns an error.
+    pub const fn with_isolated_failures(mut self) -> Self {
+        self.isolate_failures = true;
+        self
     }
 
-    /// Updates the current buffer capacity and notifies all `is_ready` watchers of the manager's
-    /// readiness to receive notifications.
-    fn update_capacity(&self) {
-        let capacity = self.max_capacity.saturating_sub(self.buffer.len());
-        self.current_capacity.store(capacity, Ordering::Relaxed);
-        self.metrics.current_capacity.set(capacity as f64);
-        self.metrics.buffer_size.set(self.buffer.len() as f64);
-
-        // we can safely ignore if the channel is closed, since the manager always holds it open
-        // internally
-        let _ = self.is_ready.send(capacity > 0);
-    }
+    /// Sets the storage to persist the finished heights of `ExEx`'s across restarts.
+    ///
+    /// The finished heights already present in the storage are loaded into the `ExEx`'s that have
+    /// not emitted a [`ExExEvent::FinishedHeight`] yet, so that the notifications already
+    /// processed by them before the restart are not delivered again.
+    pub fn with_finished_heights_storage(
+        mut self,
+        storage: FinishedHeightsStorage,
+    ) -> eyre::Result<Self> {
+        let finished_heights = storage.read()?;
+        for exex in &mut self.exex_handles {
+            if exex.finished_height.is_none() {
+                exex.finished_height = finished_heights.get(&exex.id).copied();
+                debug!(target: "exex::manager", exex_id = %exex.id, finished_height = ?exex.finished_height, "Loaded finished height");
+            }
+        }
 
-    /// Pushes a new notification into the managers internal buffer, assigning the notification a
-    /// unique ID.
-    fn push_notification(&mut self, notification: ExExNotification) {
-        let next_id = self.next_id;
-        self.buffer.push_back((next_id, notification));
-        self.next_id += 1;
+        self.finished_heights_storage = Some(storage);
+        self.stored_finished_heights = finished_heights;
+        Ok(self)
     }
-}
 
-impl<P> ExExManager<P>
-where
-    P: HeaderProvider,
-{
-    /// Finalizes the WAL according to the passed finalized header.
+    /// Replays the notifications from the WAL that were committed, but not yet processed by the
+    /// `ExEx`'s, e.g. because the node crashed before the `ExEx`'s consumed them.
     ///
-    /// This function checks if all ExExes are on the canonical chain and finalizes the WAL if
-    /// necessary.
-    fn finalize_wal(&self, finalized_header: SealedHeader) -> eyre::Result<()> {
-        debug!(target: "exex::manager", header = ?finalized_header.num_hash(), "Received finalized header");
+    /// Each `ExEx` receives the notifications after the last one containing its finished height,
+    /// or all notifications from the WAL if its finished height is not set or not found in the
+    /// WAL. Call this after [`Self::with_finished_heights_storage`], so that the finished heights
+    /// from before the restart are taken into account.
+    ///
+    /// The notifications are not buffered in the manager, so they don't take up its capacity, but
+    /// are read from the WAL in the background while they are delivered, the same way as for
+    /// replays requested with
+    /// [`ExExContext::request_replay_from`](crate::ExExContext::request_replay_from).
+    pub fn with_wal_replay(mut self) -> eyre::Result<Self> {
+        let Some(file_ids) = self.wal.file_ids() else { return Ok(self) };
+
+        if let Some(notification) = self.wal.read_notification(*file_ids.end())? {
+            self.update_head(&notification);
+        }
 
-        // Check if all ExExes are on the canonical chain
-        let exex_finished_heights = self
-            .exex_handles
-            .iter()
-            // Get ID and finished height for each ExEx
-            .map(|exex_handle| (&exex_handle.id, exex_handle.finished_height))
-            // Deduplicate all hashes
-            .unique_by(|(_, num_hash)| num_hash.map(|num_hash| num_hash.hash))
-            // Check if hashes are canonical
-            .map(|(exex_id, num_hash)| {
-                num_hash.map_or(Ok((exex_id, num_hash, false)), |num_hash| {
-                    self.provider
-                        .is_known(&num_hash.hash)
-                        // Save the ExEx ID, finished height, and whether the hash is canonical
-                        .map(|is_canonical| (exex_id, Some(num_hash), is_canonical))
+        for exex in &mut self.exex_handles {
+            let first_file_id = exex
+                .finished_height
+                .and_then(|finished_height| {
+                    self.wal.file_id_by_committed_block_hash(&finished_height.hash)
                 })
-            })
-            // We collect here to be able to log the unfinalized ExExes below
-            .collect::<Result<Vec<_>, _>>()?;
-        if exex_finished_heights.iter().all(|(_, _, is_canonical)| *is_canonical) {
-            // If there is a finalized header and all ExExs are on the canonical chain, finalize
-            // the WAL with either the lowest finished height among all ExExes, or finalized header
-            // – whichever is lower.
-            let lowest_finished_height = exex_finished_heights
-                .iter()
-                .copied()
-                .filter_map(|(_, num_hash, _)| num_hash)
-                .chain([(finalized_header.num_hash())])
-                .min_by_key(|num_hash| num_hash.number)
-                .unwrap();
+                .map_or(*file_ids.start(), |file_id| file_id + 1);
+            if first_file_id > *file_ids.end() {
+                debug!(target: "exex::manager", exex_id = %exex.id, "No notifications to replay from the WAL");
+                continue
+            }
 
-            self.wal.finalize(lowest_finished_height)?;
-        } else {
-            let unfinalized_exexes = exex_finished_heights
-                .into_iter()
-                .filter_map(|(exex_id, num_hash, is_canonical)| {
-                    is_canonical.not().then_some((exex_id, num_hash))
-                })
-                .format_with(", ", |(exex_id, num_hash), f| {
-                    f(&format_args!("{exex_id} = {num_hash:?}"))
-                })
-                // We need this because `debug!` uses the argument twice when formatting the final
-                // log message, but the result of `format_with` can only be used once
-                .to_string();
+            let exex_file_ids = first_file_id..=*file_ids.end();
             debug!(
                 target: "exex::manager",
-                %unfinalized_exexes,
-                "Not all ExExes are on the canonical chain, can't finalize the WAL"
+                exex_id = %exex.id,
+                file_ids = ?exex_file_ids,
+                "Replaying notifications from the WAL"
             );
+            exex.replay = Some(ExExReplay::new(exex_file_ids));
         }
 
-        Ok(())
+        Ok(self)
     }
-}
-
-impl<P> Future for ExExManager<P>
-where
-    P: HeaderProvider + Unpin + 'static,
-{
-    type Output = eyre::Result<()>;
 
-    /// Main loop of the [`ExExManager`]. The order of operations is as follows:
-    /// 1. Handle incoming ExEx events. We do it before finalizing the WAL, because it depends on
-    ///    the latest state of [`ExExEvent::FinishedHeight`] events.
-    /// 2. Finalize the WAL with the finalized header, if necessary.
-    /// 3. Drain [`ExExManagerHandle`] notifications, push them to the internal buffer and update
-    ///    the internal buffer capacity.
-    /// 5. Send notifications from the internal buffer to those ExExes that are ready to receive new
-    ///    notifications.
-    /// 5. Remove notifications from the internal buffer that have been sent to **all** ExExes and
-    ///    update the internal buffer capacity.
-    /// 6. Update the channel with the lowest [`FinishedExExHeight`] among all ExExes.
-    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
-        let this = self.get_mut();
+    /// Updates the finished heights to persist with the ones of all registered `ExEx`'s, if the
+    /// storage is set.
+    ///
+    /// The finished heights of `ExEx`'s that are not registered are kept, so that they can resume
+    /// from them when registered again, e.g. after a restart.
+    fn update_stored_finished_heights(&mut self) {
+        if self.finished_heights_storage.is_none() {
+            return
+        }
 
-        // Handle incoming ExEx events
-        for exex in &mut this.exex_handles {
-            while let Poll::Ready(Some(event)) = exex.receiver.poll_recv(cx) {
-                debug!(target: "exex::manager", exex_id = %exex.id, ?event, "Received event from ExEx");
-                exex.metrics.events_sent_total.increment(1);
-                match event {
-                    ExExEvent::FinishedHeight(height) => exex.finished_height = Some(height),
+        for exex in &self.exex_handles {
+            if let Some(height) = exex.finished_height {
+                if self.stored_finished_heights.get(&exex.id) != Some(&height) {
+                    self.stored_finished_heights.insert(exex.id.clone(), height);
+                    self.stored_finished_heights_changed = true;
                 }
             }
         }
+    }
 
-        // Drain the finalized header stream and finalize the WAL with the last header
-        let mut last_finalized_header = None;
-        while let Poll::Ready(finalized_header) = this.finalized_header_stream.poll_next_unpin(cx) {
-            last_finalized_header = finalized_header;
-        }
+    /// Writes the finished heights to the storage in a blocking task, off the poll path, if they
+    /// changed since the last write was started.
+    ///
+    /// Only one write is in progress at a time, and the changes made during it are written after
+    /// it completes. Write errors are logged, because the finished heights are written again on
+    /// the next change.
+    ///
+    /// Returns [`Poll::Ready`] when all changes are written.
+    fn poll_persist_finished_heights(&mut self, cx: &mut Context<'_>) -> Poll<()> {
+        loop {
+            if let Some(write) = &mut self.finished_heights_write {
+                if let Err(err) = ready!(Pin::new(write).poll(cx)) {
+                    error!(target: "exex::manager", %err, "Finished heights write task failed");
+                }
+                self.finished_heights_write = None;
+            }
+
+            let Some(storage) = self.finished_heights_storage.clone() else {
+                return Poll::Ready(())
+            };
+            if !self.stored_finished_heights_changed {
+                return Poll::Ready(())
+            }
+            self.stored_finished_heights_changed = false;
+
+            let finished_heights = self.stored_finished_heights.clone();
+            self.finished_heights_write = Some(tokio::task::spawn_blocking(move || {
+                if let Err(err) = storage.write(&finished_heights) {
+                    error!(target: "exex::manager", %err, "Failed to persist finished heights");
+                }
+            }));
+        }
+    }
+
+    /// Registers a new `ExEx` with the manager, e.g. after the node has started.
+    ///
+    /// The `ExEx` receives all notifications still buffered in the manager, skipping the ones below
+    /// its finished height. If the finished heights storage is set, the finished height persisted
+    /// for the `ExEx` is loaded, unless it's already set. To catch up from an earlier block than is
+    /// buffered, the `ExEx` should call [`ExExNotifications::with_head`] before consuming the
+    /// notifications.
+    ///
+    /// Until the new `ExEx` emits a [`ExExEvent::FinishedHeight`] event, the finished height of
+    /// all `ExEx`'s is [`FinishedExExHeight::NotReady`], so that nothing the new `ExEx` might
+    /// still need is pruned.
+    ///
+    /// Returns an error if an `ExEx` with the same ID is already registered.
+    pub fn register_exex(&mut self, mut exex: ExExHandle) -> Result<(), ExExRegistrationError> {
+        if self.exex_handles.iter().any(|handle| handle.id == exex.id) {
+            return Err(ExExRegistrationError::DuplicateId(exex.id))
+        }
+
+        if exex.finished_height.is_none() {
+            exex.finished_height = self.stored_finished_heights.get(&exex.id).copied();
+        }
+        if exex.finished_height.is_none() {
+            self.finished_height.send_replace(FinishedExExHeight::NotReady);
+        }
+
+        exex.next_notification_id = self.min_id;
+        debug!(target: "exex::manager", exex_id = %exex.id, finished_height = ?exex.finished_height, "Registered ExEx");
+
+        self.terminated_exexs.retain(|status| status.id != exex.id);
+        self.exex_handles.push(exex);
+        self.update_num_exexs();
+
+        Ok(())
+    }
+
+    /// Unregisters the `ExEx` with the given ID from the manager, and returns its final status.
+    ///
+    /// The stop signal of the `ExEx` is cancelled and its notifications channel is closed, and the
+    /// [`ExExEvent::FinishedHeight`] events it already sent are taken into account in the final
+    /// status. The `ExEx` is not taken into account for the finished height of all `ExEx`'s
+    /// anymore, and the notifications buffered only for it are released.
+    ///
+    /// Unlike [`ExExManagerHandle::unregister_exex`], doesn't wait for the final event of the
+    /// `ExEx`.
+    ///
+    /// Returns an error if no `ExEx` with the given ID is registered.
+    pub fn unregister_exex(&mut self, id: &str) -> Result<ExExStatus, ExExRegistrationError> {
+        let exex = self.remove_exex(id)?;
+        Ok(ExExStatus { state: ExExState::Terminated, ..self.release_exex(exex) })
+    }
+
+    /// Reports that the `ExEx` with the given ID returned an error, e.g. from its future launched
+    /// by the node.
+    ///
+    /// The `ExEx` is removed from the manager if it's still registered, like on
+    /// [`Self::unregister_exex`], and its status is reported as [`ExExState::Errored`] with the
+    /// given error message.
+    pub fn report_exex_error(&mut self, id: &str, error: String) {
+        let state = ExExState::Errored(error);
+        if let Ok(exex) = self.remove_exex(id) {
+            let status = self.release_exex(exex);
+            self.terminated_exexs.push(ExExStatus { state, ..status });
+        } else if let Some(status) = self.terminated_exexs.iter_mut().find(|status| status.id == id)
+        {
+            // The ExEx was already removed, e.g. because its notifications channel was closed
+            status.state = state;
+        } else {
+            warn!(target: "exex::manager", exex_id = %id, "Error reported for an unknown ExEx");
+        }
+    }
+
+    /// Removes the `ExEx` with the given ID from the manager, taking into account the events it
+    /// already sent, and cancels its stop signal.
+    fn remove_exex(&mut self, id: &str) -> Result<ExExHandle, ExExRegistrationError> {
+        let index = self
+            .exex_handles
+            .iter()
+            .position(|exex| exex.id == id)
+            .ok_or_else(|| ExExRegistrationError::NotFound(id.to_string()))?;
+        let mut exex = self.exex_handles.remove(index);
+        self.update_num_exexs();
+
+        exex.receive_queued_events();
+        exex.stop_signal.cancel();
+
+        Ok(exex)
+    }
+
+    /// Persists the finished height of the removed `ExEx`, if the storage is set, and returns its
+    /// final status. Dropping the handle closes the notifications channel of the `ExEx`.
+    fn release_exex(&mut self, exex: ExExHandle) -> ExExStatus {
+        if let (Some(_), Some(height)) = (&self.finished_heights_storage, exex.finished_height) {
+            if self.stored_finished_heights.get(&exex.id) != Some(&height) {
+                self.stored_finished_heights.insert(exex.id.clone(), height);
+                self.stored_finished_heights_changed = true;
+            }
+        }
+        debug!(target: "exex::manager", exex_id = %exex.id, finished_height = ?exex.finished_height, "Removed ExEx");
+
+        exex.status()
+    }
+
+    /// Completes the unregistration of the `ExEx`'s that emitted their final
+    /// [`ExExEvent::FinishedHeight`] event or exited since they were signaled to stop, or didn't
+    /// do either until the deadline.
+    fn poll_unregistering_exexs(&mut self, cx: &mut Context<'_>) {
+        for idx in (0..self.unregistering_exexs.len()).rev() {
+            let unregistering = &mut self.unregistering_exexs[idx];
+
+            let mut stopped = false;
+            loop {
+                match unregistering.exex.receiver.poll_recv(cx) {
+                    Poll::Ready(Some(ExExEvent::FinishedHeight(height))) => {
+                        unregistering.exex.finished_height = Some(height);
+                        stopped = true;
+                    }
+                    Poll::Ready(None) => {
+                        stopped = true;
+                        break
+                    }
+                    Poll::Pending => break,
+                }
+            }
+
+            if !stopped {
+                if unregistering.deadline.as_mut().poll(cx).is_pending() {
+                    continue
+                }
+                warn!(
+                    target: "exex::manager",
+                    exex_id = %unregistering.exex.id,
+                    "ExEx didn't emit its final finished height in time, unregistering it anyway"
+                );
+            }
+
+            let UnregisteringExEx { exex, tx, .. } = self.unregistering_exexs.swap_remove(idx);
+            let _ =
+                tx.send(Ok(ExExStatus { state: ExExState::Terminated, ..self.release_exex(exex) }));
+        }
+    }
+
+    /// Updates the number of `ExEx`'s known to the [`ExExManagerHandle`]s and the metrics.
+    fn update_num_exexs(&self) {
+        let num_exexs = self.exex_handles.len();
+        self.handle.num_exexs.store(num_exexs, Ordering::Relaxed);
+        self.metrics.num_exexs.set(num_exexs as f64);
+    }
+
+    /// Returns the statuses of all `ExEx`'s, including the ones terminated by the manager or
+    /// exited.
+    pub fn exex_statuses(&self) -> Vec<ExExStatus> {
+        self.exex_handles
+            .iter()
+            .map(ExExHandle::status)
+            .chain(self.terminated_exexs.iter().cloned())
+            .collect()
+    }
+
+    /// Returns the handle to the manager.
+    pub fn handle(&self) -> ExExManagerHandle {
+        self.handle.clone()
+    }
+
+    /// Updates the current buffer capacity and notifies all `is_ready` watchers of the manager's
+    /// readiness to receive notifications.
+    fn update_capacity(&self) {
+        let capacity =
+            if self.is_blocked() { 0 } else { self.max_capacity.saturating_sub(self.buffer.len()) };
+        self.current_capacity.store(capacity, Ordering::Relaxed);
+        self.metrics.current_capacity.set(capacity as f64);
+        self.metrics.buffer_size.set(self.buffer.len() as f64);
+
+        // we can safely ignore if the channel is closed, since the manager always holds it open
+        // internally
+        let _ = self.is_ready.send(capacity > 0);
+    }
+
+    /// Returns `true` if any `ExEx` with the [`ExExOverflowPolicy::Block`] policy reached its max
+    /// number of buffered notifications.
+    fn is_blocked(&self) -> bool {
+        self.exex_handles.iter().any(|exex| exex.is_blocking(self.next_id))
+    }
+
+    /// Applies the [`ExExOverflowPolicy`] of each `ExEx` that exceeded its max number of buffered
+    /// notifications.
+    fn apply_overflow_policies(&mut self) {
+        let next_id = self.next_id;
+        self.exex_handles.retain_mut(|exex| {
+            let Some(max_buffered_notifications) = exex.max_buffered_notifications else {
+                return true
+            };
+            let buffered_notifications = exex.buffered_notifications(next_id);
+            if buffered_notifications <= max_buffered_notifications {
+                return true
+            }
+
+            match exex.overflow_policy {
+                ExExOverflowPolicy::Block => true,
+                ExExOverflowPolicy::DropOldest => {
+                    let dropped = buffered_notifications - max_buffered_notifications;
+                    warn!(
+                        target: "exex::manager",
+                        exex_id = %exex.id,
+                        %dropped,
+                        "Dropping oldest notifications not yet delivered to ExEx"
+                    );
+                    exex.next_notification_id += dropped;
+                    exex.metrics.notifications_dropped_total.increment(dropped as u64);
+                    true
+                }
+                ExExOverflowPolicy::Error => {
+                    error!(
+                        target: "exex::manager",
+                        exex_id = %exex.id,
+                        %buffered_notifications,
+                        %max_buffered_notifications,
+                        "ExEx exceeded the max number of buffered notifications, terminating it"
+                    );
+                    exex.stop_signal.cancel();
+                    self.terminated_exexs
+                        .push(ExExStatus { state: ExExState::Terminated, ..exex.status() });
+                    false
+                }
+            }
+        });
+        self.update_num_exexs();
+    }
+
+    /// Checks the progress of `ExEx`'s with a watchdog, and applies the [`ExExWatchdogAction`] of
+    /// those that made no progress for their watchdog timeout.
+    fn apply_watchdogs(&mut self, cx: &mut Context<'_>) {
+        let next_id = self.next_id;
+        let head = *self.head.borrow();
+        self.exex_handles.retain_mut(|exex| {
+            let has_unprocessed_notifications = exex.has_unprocessed_notifications(next_id, head);
+            let Some(watchdog) = &mut exex.watchdog else { return true };
+
+            if !has_unprocessed_notifications {
+                watchdog.last_progress = Instant::now();
+            }
+
+            let timeout = watchdog.timeout;
+            let interval = watchdog.interval.get_or_insert_with(|| {
+                let mut interval = tokio::time::interval(timeout);
+                interval.set_missed_tick_behavior(MissedTickBehavior::Delay);
+                interval
+            });
+            if interval.poll_tick(cx).is_pending() {
+                return true
+            }
+
+            let elapsed = watchdog.last_progress.elapsed();
+            if elapsed < timeout {
+                return true
+            }
+
+            match watchdog.action {
+                ExExWatchdogAction::Warn => {
+                    warn!(target: "exex::manager", exex_id = %exex.id, ?elapsed, "ExEx made no progress");
+                    true
+                }
+                ExExWatchdogAction::Terminate => {
+                    error!(target: "exex::manager", exex_id = %exex.id, ?elapsed, "ExEx made no progress, terminating it");
+                    exex.stop_signal.cancel();
+                    self.terminated_exexs
+                        .push(ExExStatus { state: ExExState::Terminated, ..exex.status() });
+                    false
+                }
+            }
+        });
+        self.update_num_exexs();
+    }
+
+    /// Updates the latest block number of the node with the given notification.
+    fn update_head(&self, notification: &ExExNotification) {
+        if let Some(committed_chain) = notification.committed_chain() {
+            self.head.send_replace(Some(committed_chain.tip().number));
+        } else if let Some(reverted_chain) = notification.reverted_chain() {
+            self.head.send_replace(Some(reverted_chain.first().number.saturating_sub(1)));
+        }
+    }
+
+    /// Returns the IDs of running `ExEx`'s that have not processed all notifications up to the
+    /// node head, i.e. have pending notifications in the buffer or haven't emitted a matching
+    /// [`ExExEvent::FinishedHeight`] yet.
+    fn undrained_exexs(&self) -> Vec<String> {
+        let head = *self.head.borrow();
+        self.exex_handles
+            .iter()
+            .filter(|exex| exex.status().state.is_running())
+            .filter(|exex| exex.has_unprocessed_notifications(self.next_id, head))
+            .map(|exex| exex.id.clone())
+            .collect()
+    }
+
+    /// Pushes a new notification into the managers internal buffer, assigning the notification a
+    /// unique ID.
+    fn push_notification(&mut self, notification: ExExNotification) {
+        let next_id = self.next_id;
+        self.buffer.push_back((next_id, notification));
+        self.next_id += 1;
+    }
+}
+
+impl<P> ExExManager<P>
+where
+    P: HeaderProvider,
+{
+    /// Finalizes the WAL according to the passed finalized header.
+    ///
+    /// This function checks if all ExExes are on the canonical chain and finalizes the WAL if
+    /// necessary.
+    fn finalize_wal(&self, finalized_header: SealedHeader) -> eyre::Result<()> {
+        debug!(target: "exex::manager", header = ?finalized_header.num_hash(), "Received finalized header");
+
+        // Check if all ExExes are on the canonical chain
+        let exex_finished_heights = self
+            .exex_handles
+            .iter()
+            // Get ID and finished height for each ExEx
+            .map(|exex_handle| (&exex_handle.id, exex_handle.finished_height))
+            // Deduplicate all hashes
+            .unique_by(|(_, num_hash)| num_hash.map(|num_hash| num_hash.hash))
+            // Check if hashes are canonical
+            .map(|(exex_id, num_hash)| {
+                num_hash.map_or(Ok((exex_id, num_hash, false)), |num_hash| {
+                    self.provider
+                        .is_known(&num_hash.hash)
+                        // Save the ExEx ID, finished height, and whether the hash is canonical
+                        .map(|is_canonical| (exex_id, Some(num_hash), is_canonical))
+                })
+            })
+            // We collect here to be able to log the unfinalized ExExes below
+            .collect::<Result<Vec<_>, _>>()?;
+        if exex_finished_heights.iter().all(|(_, _, is_canonical)| *is_canonical) {
+            // If there is a finalized header and all ExExs are on the canonical chain, finalize
+            // the WAL with either the lowest finished height among all ExExes, or finalized header
+            // – whichever is lower.
+            let lowest_finished_height = exex_finished_heights
+                .iter()
+                .copied()
+                .filter_map(|(_, num_hash, _)| num_hash)
+                .chain([(finalized_header.num_hash())])
+                .min_by_key(|num_hash| num_hash.number)
+                .unwrap();
+
+            self.wal.finalize(lowest_finished_height)?;
+        } else {
+            let unfinalized_exexes = exex_finished_heights
+                .into_iter()
+                .filter_map(|(exex_id, num_hash, is_canonical)| {
+                    is_canonical.not().then_some((exex_id, num_hash))
+                })
+                .format_with(", ", |(exex_id, num_hash), f| {
+                    f(&format_args!("{exex_id} = {num_hash:?}"))
+                })
+                // We need this because `debug!` uses the argument twice when formatting the final
+                // log message, but the result of `format_with` can only be used once
+                .to_string();
+            debug!(
+                target: "exex::manager",
+                %unfinalized_exexes,
+                "Not all ExExes are on the canonical chain, can't finalize the WAL"
+            );
+        }
+
+        Ok(())
+    }
+}
+
+impl<P> ExExManager<P>
+where
+    P: HeaderProvider + Unpin + 'static,
+{
+    /// Drives the manager until all `ExEx`'s have processed the buffered notifications and
+    /// emitted a [`ExExEvent::FinishedHeight`] for the node head, or until the timeout elapses.
+    ///
+    /// Intended to be called on shutdown, so that the notifications that were buffered but not
+    /// processed yet don't have to be processed again after the restart. Also waits for the
+    /// finished heights to be persisted, if the storage is set.
+    ///
+    /// The `ExEx`'s that exit while draining are removed from the manager, as with
+    /// [`Self::with_isolated_failures`].
+    ///
+    /// Returns the IDs of `ExEx`'s that didn't drain in time.
+    pub async fn drain(&mut self, timeout: Duration) -> eyre::Result<Vec<String>> {
+        self.draining = true;
+        let drain = poll_fn(|cx| {
+            if let Poll::Ready(result) = Pin::new(&mut *self).poll(cx) {
+                return Poll::Ready(result)
+            }
+
+            if self.undrained_exexs().is_empty() &&
+                self.poll_persist_finished_heights(cx).is_ready()
+            {
+                Poll::Ready(Ok(()))
+            } else {
+                Poll::Pending
+            }
+        });
+
+        if let Ok(result) = tokio::time::timeout(timeout, drain).await {
+            result?;
+        }
+
+        let undrained = self.undrained_exexs();
+        for exex_id in &undrained {
+            warn!(target: "exex::manager", %exex_id, ?timeout, "ExEx didn't drain in time");
+        }
+
+        Ok(undrained)
+    }
+}
+
+impl<P> Future for ExExManager<P>
+where
+    P: HeaderProvider + Unpin + 'static,
+{
+    type Output = eyre::Result<()>;
+
+    /// Main loop of the [`ExExManager`]. The order of operations is as follows:
+    /// 1. Handle commands from the [`ExExManagerHandle`]s, i.e. registering and unregistering
+    ///    ExExes and reporting their errors, the final events of the unregistering ExExes, and
+    ///    incoming ExEx events. We do it before finalizing the WAL, because it depends on the
+    ///    latest state of [`ExExEvent::FinishedHeight`] events. Persist the updated finished
+    ///    heights in the background, if the storage is set.
+    /// 2. Finalize the WAL with the finalized header, if necessary.
+    /// 3. Drain [`ExExManagerHandle`] notifications, push them to the internal buffer and update
+    ///    the internal buffer capacity. Stop draining if any `ExEx` with
+    ///    [`ExExOverflowPolicy::Block`] policy reached its max number of buffered notifications.
+    /// 4. Apply the [`ExExOverflowPolicy`] of ExExes that exceeded their max number of buffered
+    ///    notifications, and the [`ExExWatchdogAction`] of ExExes that made no progress for their
+    ///    watchdog timeout.
+    /// 5. Send notifications from the internal buffer to those ExExes that are ready to receive new
+    ///    notifications. Remove the ExExes that exited, i.e. closed their notifications channel.
+    /// 6. Remove notifications from the internal buffer that have been sent to **all** ExExes and
+    ///    update the internal buffer capacity.
+    /// 7. Update the channel with the lowest [`FinishedExExHeight`] among all ExExes.
+    /// 8. Update the channels with the [`ExExLag`] and [`ExExStatus`] of each `ExEx`.
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let this = self.get_mut();
+
+        // Handle commands
+        while let Poll::Ready(Some(command)) = this.command_rx.poll_recv(cx) {
+            match command {
+                ExExManagerCommand::Register { exex, tx } => {
+                    let _ = tx.send(this.register_exex(exex));
+                }
+                ExExManagerCommand::Unregister { id, timeout, tx } => match this.remove_exex(&id) {
+                    Ok(exex) => this.unregistering_exexs.push(UnregisteringExEx {
+                        exex,
+                        deadline: Box::pin(tokio::time::sleep(timeout)),
+                        tx,
+                    }),
+                    Err(err) => {
+                        let _ = tx.send(Err(err));
+                    }
+                },
+                ExExManagerCommand::ReportError { id, error } => {
+                    this.report_exex_error(&id, error);
+                }
+            }
+        }
+        this.poll_unregistering_exexs(cx);
+
+        // Handle incoming ExEx events
+        let mut finished_heights_updated = false;
+        for exex in &mut this.exex_handles {
+            while let Poll::Ready(Some(event)) = exex.receiver.poll_recv(cx) {
+                debug!(target: "exex::manager", exex_id = %exex.id, ?event, "Received event from ExEx");
+                exex.metrics.events_sent_total.increment(1);
+                match event {
+                    ExExEvent::FinishedHeight(height) => {
+                        exex.finished_height = Some(height);
+                        if let Some(watchdog) = &mut exex.watchdog {
+                            watchdog.last_progress = Instant::now();
+                        }
+                    }
+                }
+                finished_heights_updated = true;
+            }
+            while let Poll::Ready(Some(ExExReplayRequest { from, tx })) =
+                exex.replay_rx.poll_recv(cx)
+            {
+                let _ = tx.send(exex.replay_from(&this.wal, this.next_id, from));
+            }
+        }
+        if finished_heights_updated {
+            this.update_stored_finished_heights();
+        }
+        let _ = this.poll_persist_finished_heights(cx);
+
+        // Drain the finalized header stream and finalize the WAL with the last header
+        let mut last_finalized_header = None;
+        while let Poll::Ready(finalized_header) = this.finalized_header_stream.poll_next_unpin(cx) {
+            last_finalized_header = finalized_header;
+        }
         if let Some(header) = last_finalized_header {
             this.finalize_wal(header)?;
         }
 
         // Drain handle notifications
-        while this.buffer.len() < this.max_capacity {
+        while this.buffer.len() < this.max_capacity && !this.is_blocked() {
             if let Poll::Ready(Some(notification)) = this.handle_rx.poll_recv(cx) {
                 debug!(
                     target: "exex::manager",
@@ -437,17 +1469,24 @@ where
                     "Received new notification"
                 );
                 this.wal.commit(&notification)?;
+                this.update_head(&notification);
                 this.push_notification(notification);
                 continue
             }
             break
         }
 
+        // Apply overflow policies and watchdogs
+        this.apply_overflow_policies();
+        this.apply_watchdogs(cx);
+
         // Update capacity
         this.update_capacity();
 
-        // Advance all poll senders
-        let mut min_id = usize::MAX;
+        // Advance all poll senders. All buffered notifications are processed if there are no
+        // ExExes left, so that an ExEx registered later starts from the next notification.
+        let mut min_id = this.next_id;
+        let mut exexs_exited = false;
         for idx in (0..this.exex_handles.len()).rev() {
             let mut exex = this.exex_handles.swap_remove(idx);
 
@@ -457,16 +1496,42 @@ where
                 .next_notification_id
                 .checked_sub(this.min_id)
                 .expect("exex expected notification ID outside the manager's range");
-            if let Some(notification) = this.buffer.get(notification_index) {
-                if let Poll::Ready(Err(err)) = exex.send(cx, notification) {
+            let poll = match exex.send_replayed(cx, &this.wal) {
+                Some(poll) => poll,
+                None => this
+                    .buffer
+                    .get(notification_index)
+                    .map_or(Poll::Pending, |notification| exex.send(cx, notification)),
+            };
+            if let Poll::Ready(Err(err)) = poll {
+                if !this.isolate_failures && !this.draining {
                     // The channel was closed, which is irrecoverable for the manager
                     return Poll::Ready(Err(err.into()))
                 }
+
+                // The channel was closed, i.e. the ExEx exited, so it can't receive notifications
+                // anymore and is removed from the manager. It's only expected to exit while
+                // the manager is draining on shutdown, otherwise it failed.
+                error!(target: "exex::manager", exex_id = %exex.id, draining = %this.draining, "ExEx notifications channel closed, removing it");
+                exex.receive_queued_events();
+                let status = this.release_exex(exex);
+                let state = if this.draining {
+                    ExExState::Exited
+                } else {
+                    ExExState::Errored("notifications channel closed".to_string())
+                };
+                this.terminated_exexs.push(ExExStatus { state, ..status });
+                exexs_exited = true;
+                continue
             }
             min_id = min_id.min(exex.next_notification_id);
             this.exex_handles.push(exex);
         }
 
+        if exexs_exited {
+            this.update_num_exexs();
+        }
+
         // Remove processed buffered notifications
         debug!(target: "exex::manager", %min_id, "Updating lowest notification id in buffer");
         this.buffer.retain(|&(id, _)| id >= min_id);
@@ -476,13 +1541,39 @@ where
         this.update_capacity();
 
         // Update watch channel block number
-        let finished_height = this.exex_handles.iter_mut().try_fold(u64::MAX, |curr, exex| {
-            exex.finished_height.map_or(Err(()), |height| Ok(height.number.min(curr)))
-        });
-        if let Ok(finished_height) = finished_height {
-            let _ = this.finished_height.send(FinishedExExHeight::Height(finished_height));
+        if this.exex_handles.is_empty() {
+            // All ExExes were unregistered or terminated
+            let _ = this.finished_height.send(FinishedExExHeight::NoExExs);
+        } else {
+            let finished_height = this.exex_handles.iter_mut().try_fold(u64::MAX, |curr, exex| {
+                exex.finished_height.map_or(Err(()), |height| Ok(height.number.min(curr)))
+            });
+            if let Ok(finished_height) = finished_height {
+                let _ = this.finished_height.send(FinishedExExHeight::Height(finished_height));
+            }
         }
 
+        // Update lags of all ExExes
+        let head = *this.head.borrow();
+        let lags = this
+            .exex_handles
+            .iter()
+            .map(|exex| (exex.id.clone(), exex.update_lag(this.next_id, head)))
+            .collect::<BTreeMap<_, _>>();
+        this.lags.send_if_modified(|current| {
+            let modified = *current != lags;
+            *current = lags;
+            modified
+        });
+
+        // Update statuses of all ExExes
+        let statuses = this.exex_statuses();
+        this.statuses.send_if_modified(|current| {
+            let modified = *current != statuses;
+            *current = statuses;
+            modified
+        });
+
         Poll::Pending
     }
 }
@@ -492,8 +1583,10 @@ where
 pub struct ExExManagerHandle {
     /// Channel to send notifications to the `ExEx` manager.
     exex_tx: UnboundedSender<ExExNotification>,
+    /// Channel to send commands to the `ExEx` manager.
+    command_tx: UnboundedSender<ExExManagerCommand>,
     /// The number of `ExEx`'s running on the node.
-    num_exexs: usize,
+    num_exexs: Arc<AtomicUsize>,
     /// A watch channel denoting whether the manager is ready for new notifications or not.
     ///
     /// This is stored internally alongside a `ReusableBoxFuture` representation of the same value.
@@ -507,6 +1600,13 @@ pub struct ExExManagerHandle {
     current_capacity: Arc<AtomicUsize>,
     /// The finished height of all `ExEx`'s.
     finished_height: watch::Receiver<FinishedExExHeight>,
+    /// The latest block number of the node, according to the notifications received by the
+    /// manager.
+    head: watch::Receiver<Option<BlockNumber>>,
+    /// The lag of each `ExEx` behind the node, keyed by the `ExEx` ID.
+    lags: watch::Receiver<BTreeMap<String, ExExLag>>,
+    /// The statuses of all `ExEx`'s.
+    statuses: watch::Receiver<Vec<ExExStatus>>,
 }
 
 impl ExExManagerHandle {
@@ -517,16 +1617,24 @@ impl ExExManagerHandle {
     /// The handle will always be ready, and have a capacity of 0.
     pub fn empty() -> Self {
         let (exex_tx, _) = mpsc::unbounded_channel();
+        let (command_tx, _) = mpsc::unbounded_channel();
         let (_, is_ready_rx) = watch::channel(true);
         let (_, finished_height_rx) = watch::channel(FinishedExExHeight::NoExExs);
+        let (_, head_rx) = watch::channel(None);
+        let (_, lags_rx) = watch::channel(BTreeMap::new());
+        let (_, statuses_rx) = watch::channel(Vec::new());
 
         Self {
             exex_tx,
-            num_exexs: 0,
+            command_tx,
+            num_exexs: Arc::new(AtomicUsize::new(0)),
             is_ready_receiver: is_ready_rx.clone(),
             is_ready: ReusableBoxFuture::new(make_wait_future(is_ready_rx)),
             current_capacity: Arc::new(AtomicUsize::new(0)),
             finished_height: finished_height_rx,
+            head: head_rx,
+            lags: lags_rx,
+            statuses: statuses_rx,
         }
     }
 
@@ -537,596 +1645,1729 @@ impl ExExManagerHandle {
         self.exex_tx.send(notification)
     }
 
-    /// Asynchronously send a notification over the channel to all execution extensions.
-    ///
-    /// The returned future resolves when the notification has been delivered. If there is no
-    /// capacity in the channel, the future will wait.
-    pub async fn send_async(
-        &mut self,
-        notification: ExExNotification,
-    ) -> Result<(), SendError<ExExNotification>> {
-        self.ready().await;
-        self.exex_tx.send(notification)
-    }
+    /// Asynchronously send a notification over the channel to all execution extensions.
+    ///
+    /// The returned future resolves when the notification has been delivered. If there is no
+    /// capacity in the channel, the future will wait.
+    pub async fn send_async(
+        &mut self,
+        notification: ExExNotification,
+    ) -> Result<(), SendError<ExExNotification>> {
+        self.ready().await;
+        self.exex_tx.send(notification)
+    }
+
+    /// Get the current capacity of the `ExEx` manager's internal notification buffer.
+    pub fn capacity(&self) -> usize {
+        self.current_capacity.load(Ordering::Relaxed)
+    }
+
+    /// Whether there is capacity in the `ExEx` manager's internal notification buffer.
+    ///
+    /// If this returns `false`, the owner of the handle should **NOT** send new notifications over
+    /// the channel until the manager is ready again, as this can lead to unbounded memory growth.
+    pub fn has_capacity(&self) -> bool {
+        self.capacity() > 0
+    }
+
+    /// Returns `true` if there are `ExEx`'s installed in the node.
+    pub fn has_exexs(&self) -> bool {
+        self.num_exexs.load(Ordering::Relaxed) > 0
+    }
+
+    /// Registers a new `ExEx` with the running manager. See [`ExExManager::register_exex`].
+    ///
+    /// The manager must have been created with at least one `ExEx`, otherwise it's not running and
+    /// no notifications are sent to it.
+    pub async fn register_exex(&self, exex: ExExHandle) -> Result<(), ExExRegistrationError> {
+        let (tx, rx) = oneshot::channel();
+        self.command_tx
+            .send(ExExManagerCommand::Register { exex, tx })
+            .map_err(|_| ExExRegistrationError::ManagerClosed)?;
+        rx.await.map_err(|_| ExExRegistrationError::ManagerClosed)?
+    }
+
+    /// Unregisters the `ExEx` with the given ID from the running manager, and returns its final
+    /// status. See [`ExExManager::unregister_exex`].
+    ///
+    /// After the stop signal of the `ExEx` is cancelled, waits up to `timeout` for it to emit its
+    /// final [`ExExEvent::FinishedHeight`] event or exit, so that the final status and the
+    /// persisted finished height account for it. See [`DEFAULT_EXEX_UNREGISTER_TIMEOUT`].
+    pub async fn unregister_exex(
+        &self,
+        id: impl Into<String>,
+        timeout: Duration,
+    ) -> Result<ExExStatus, ExExRegistrationError> {
+        let (tx, rx) = oneshot::channel();
+        self.command_tx
+            .send(ExExManagerCommand::Unregister { id: id.into(), timeout, tx })
+            .map_err(|_| ExExRegistrationError::ManagerClosed)?;
+        rx.await.map_err(|_| ExExRegistrationError::ManagerClosed)?
+    }
+
+    /// Reports that the `ExEx` with the given ID returned an error to the running manager. See
+    /// [`ExExManager::report_exex_error`].
+    pub fn report_exex_error(&self, id: impl Into<String>, error: String) {
+        let _ = self.command_tx.send(ExExManagerCommand::ReportError { id: id.into(), error });
+    }
+
+    /// The finished height of all `ExEx`'s.
+    pub fn finished_height(&self) -> watch::Receiver<FinishedExExHeight> {
+        self.finished_height.clone()
+    }
+
+    /// The latest block number of the node, according to the notifications received by the
+    /// manager.
+    pub fn head(&self) -> watch::Receiver<Option<BlockNumber>> {
+        self.head.clone()
+    }
+
+    /// The lag of each `ExEx` behind the node, keyed by the `ExEx` ID.
+    pub fn lags(&self) -> watch::Receiver<BTreeMap<String, ExExLag>> {
+        self.lags.clone()
+    }
+
+    /// Returns the lag of the `ExEx` with the given ID, if it exists.
+    pub fn lag(&self, exex_id: &str) -> Option<ExExLag> {
+        self.lags.borrow().get(exex_id).copied()
+    }
+
+    /// The statuses of all `ExEx`'s.
+    pub fn statuses(&self) -> watch::Receiver<Vec<ExExStatus>> {
+        self.statuses.clone()
+    }
+
+    /// Wait until the manager is ready for new notifications.
+    pub async fn ready(&mut self) {
+        poll_fn(|cx| self.poll_ready(cx)).await
+    }
+
+    /// Wait until the manager is ready for new notifications.
+    pub fn poll_ready(&mut self, cx: &mut Context<'_>) -> Poll<()> {
+        let rx = ready!(self.is_ready.poll(cx));
+        self.is_ready.set(make_wait_future(rx));
+        Poll::Ready(())
+    }
+}
+
+/// Creates a future that resolves once the given watch channel receiver is true.
+async fn make_wait_future(mut rx: watch::Receiver<bool>) -> watch::Receiver<bool> {
+    // NOTE(onbjerg): We can ignore the error here, because if the channel is closed, the node
+    // is shutting down.
+    let _ = rx.wait_for(|ready| *ready).await;
+    rx
+}
+
+impl Clone for ExExManagerHandle {
+    fn clone(&self) -> Self {
+        Self {
+            exex_tx: self.exex_tx.clone(),
+            command_tx: self.command_tx.clone(),
+            num_exexs: self.num_exexs.clone(),
+            is_ready_receiver: self.is_ready_receiver.clone(),
+            is_ready: ReusableBoxFuture::new(make_wait_future(self.is_ready_receiver.clone())),
+            current_capacity: self.current_capacity.clone(),
+            finished_height: self.finished_height.clone(),
+            head: self.head.clone(),
+            lags: self.lags.clone(),
+            statuses: self.statuses.clone(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloy_primitives::B256;
+    use eyre::OptionExt;
+    use futures::{FutureExt, StreamExt};
+    use rand::Rng;
+    use reth_primitives::SealedBlockWithSenders;
+    use reth_provider::{test_utils::create_test_provider_factory, BlockWriter, Chain};
+    use reth_testing_utils::generators::{self, random_block};
+
+    fn empty_finalized_header_stream() -> ForkChoiceStream<SealedHeader> {
+        let (tx, rx) = watch::channel(None);
+        // Do not drop the sender, otherwise the receiver will always return an error
+        std::mem::forget(tx);
+        ForkChoiceStream::new(rx)
+    }
+
+    #[tokio::test]
+    async fn test_delivers_events() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        let wal = Wal::new(temp_dir.path()).unwrap();
+
+        let (mut exex_handle, event_tx, mut _notification_rx) =
+            ExExHandle::new("test_exex".to_string(), Head::default(), (), (), wal.handle());
+
+        // Send an event and check that it's delivered correctly
+        let event = ExExEvent::FinishedHeight(BlockNumHash::new(42, B256::random()));
+        event_tx.send(event).unwrap();
+        let received_event = exex_handle.receiver.recv().await.unwrap();
+        assert_eq!(received_event, event);
+    }
+
+    #[tokio::test]
+    async fn test_has_exexs() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        let wal = Wal::new(temp_dir.path()).unwrap();
+
+        let (exex_handle_1, _, _) =
+            ExExHandle::new("test_exex_1".to_string(), Head::default(), (), (), wal.handle());
+
+        assert!(!ExExManager::new((), vec![], 0, wal.clone(), empty_finalized_header_stream())
+            .handle
+            .has_exexs());
+
+        assert!(ExExManager::new((), vec![exex_handle_1], 0, wal, empty_finalized_header_stream())
+            .handle
+            .has_exexs());
+    }
+
+    #[tokio::test]
+    async fn test_has_capacity() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        let wal = Wal::new(temp_dir.path()).unwrap();
+
+        let (exex_handle_1, _, _) =
+            ExExHandle::new("test_exex_1".to_string(), Head::default(), (), (), wal.handle());
+
+        assert!(!ExExManager::new((), vec![], 0, wal.clone(), empty_finalized_header_stream())
+            .handle
+            .has_capacity());
+
+        assert!(ExExManager::new(
+            (),
+            vec![exex_handle_1],
+            10,
+            wal,
+            empty_finalized_header_stream()
+        )
+        .handle
+        .has_capacity());
+    }
+
+    #[test]
+    fn test_push_notification() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        let wal = Wal::new(temp_dir.path()).unwrap();
+
+        let (exex_handle, _, _) =
+            ExExHandle::new("test_exex".to_string(), Head::default(), (), (), wal.handle());
+
+        // Create a mock ExExManager and add the exex_handle to it
+        let mut exex_manager =
+            ExExManager::new((), vec![exex_handle], 10, wal, empty_finalized_header_stream());
+
+        // Define the notification for testing
+        let mut block1 = SealedBlockWithSenders::default();
+        block1.block.header.set_hash(B256::new([0x01; 32]));
+        block1.block.header.set_block_number(10);
+
+        let notification1 = ExExNotification::ChainCommitted {
+            new: Arc::new(Chain::new(vec![block1.clone()], Default::default(), Default::default())),
+        };
+
+        // Push the first notification
+        exex_manager.push_notification(notification1.clone());
+
+        // Verify the buffer contains the notification with the correct ID
+        assert_eq!(exex_manager.buffer.len(), 1);
+        assert_eq!(exex_manager.buffer.front().unwrap().0, 0);
+        assert_eq!(exex_manager.buffer.front().unwrap().1, notification1);
+        assert_eq!(exex_manager.next_id, 1);
+
+        // Push another notification
+        let mut block2 = SealedBlockWithSenders::default();
+        block2.block.header.set_hash(B256::new([0x02; 32]));
+        block2.block.header.set_block_number(20);
+
+        let notification2 = ExExNotification::ChainCommitted {
+            new: Arc::new(Chain::new(vec![block2.clone()], Default::default(), Default::default())),
+        };
+
+        exex_manager.push_notification(notification2.clone());
+
+        // Verify the buffer contains both notifications with correct IDs
+        assert_eq!(exex_manager.buffer.len(), 2);
+        assert_eq!(exex_manager.buffer.front().unwrap().0, 0);
+        assert_eq!(exex_manager.buffer.front().unwrap().1, notification1);
+        assert_eq!(exex_manager.buffer.get(1).unwrap().0, 1);
+        assert_eq!(exex_manager.buffer.get(1).unwrap().1, notification2);
+        assert_eq!(exex_manager.next_id, 2);
+    }
+
+    #[test]
+    fn test_update_capacity() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        let wal = Wal::new(temp_dir.path()).unwrap();
+
+        let (exex_handle, _, _) =
+            ExExHandle::new("test_exex".to_string(), Head::default(), (), (), wal.handle());
+
+        // Create a mock ExExManager and add the exex_handle to it
+        let max_capacity = 5;
+        let mut exex_manager = ExExManager::new(
+            (),
+            vec![exex_handle],
+            max_capacity,
+            wal,
+            empty_finalized_header_stream(),
+        );
+
+        // Push some notifications to fill part of the buffer
+        let mut block1 = SealedBlockWithSenders::default();
+        block1.block.header.set_hash(B256::new([0x01; 32]));
+        block1.block.header.set_block_number(10);
+
+        let notification1 = ExExNotification::ChainCommitted {
+            new: Arc::new(Chain::new(vec![block1.clone()], Default::default(), Default::default())),
+        };
+
+        exex_manager.push_notification(notification1.clone());
+        exex_manager.push_notification(notification1);
+
+        // Update capacity
+        exex_manager.update_capacity();
+
+        // Verify current capacity and metrics
+        assert_eq!(exex_manager.current_capacity.load(Ordering::Relaxed), max_capacity - 2);
+
+        // Clear the buffer and update capacity
+        exex_manager.buffer.clear();
+        exex_manager.update_capacity();
+
+        // Verify current capacity
+        assert_eq!(exex_manager.current_capacity.load(Ordering::Relaxed), max_capacity);
+    }
+
+    #[tokio::test]
+    async fn test_updates_block_height() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        let wal = Wal::new(temp_dir.path()).unwrap();
+
+        let provider_factory = create_test_provider_factory();
+
+        let (exex_handle, event_tx, mut _notification_rx) =
+            ExExHandle::new("test_exex".to_string(), Head::default(), (), (), wal.handle());
+
+        // Check initial block height
+        assert!(exex_handle.finished_height.is_none());
+
+        // Update the block height via an event
+        let block = BlockNumHash::new(42, B256::random());
+        event_tx.send(ExExEvent::FinishedHeight(block)).unwrap();
+
+        // Create a mock ExExManager and add the exex_handle to it
+        let exex_manager = ExExManager::new(
+            provider_factory,
+            vec![exex_handle],
+            10,
+            Wal::new(temp_dir.path()).unwrap(),
+            empty_finalized_header_stream(),
+        );
+
+        let mut cx = Context::from_waker(futures::task::noop_waker_ref());
+
+        // Pin the ExExManager to call the poll method
+        let mut pinned_manager = std::pin::pin!(exex_manager);
+        let _ = pinned_manager.as_mut().poll(&mut cx);
+
+        // Check that the block height was updated
+        let updated_exex_handle = &pinned_manager.exex_handles[0];
+        assert_eq!(updated_exex_handle.finished_height, Some(block));
+
+        // Get the receiver for the finished height
+        let mut receiver = pinned_manager.handle.finished_height();
+
+        // Wait for a new value to be sent
+        receiver.changed().await.unwrap();
+
+        // Get the latest value
+        let finished_height = *receiver.borrow();
+
+        // The finished height should be updated to the lower block height
+        assert_eq!(finished_height, FinishedExExHeight::Height(42));
+    }
+
+    #[tokio::test]
+    async fn test_updates_block_height_lower() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        let wal = Wal::new(temp_dir.path()).unwrap();
+
+        let provider_factory = create_test_provider_factory();
+
+        // Create two `ExExHandle` instances
+        let (exex_handle1, event_tx1, _) =
+            ExExHandle::new("test_exex1".to_string(), Head::default(), (), (), wal.handle());
+        let (exex_handle2, event_tx2, _) =
+            ExExHandle::new("test_exex2".to_string(), Head::default(), (), (), wal.handle());
+
+        let block1 = BlockNumHash::new(42, B256::random());
+        let block2 = BlockNumHash::new(10, B256::random());
+
+        // Send events to update the block heights of the two handles, with the second being lower
+        event_tx1.send(ExExEvent::FinishedHeight(block1)).unwrap();
+        event_tx2.send(ExExEvent::FinishedHeight(block2)).unwrap();
+
+        let exex_manager = ExExManager::new(
+            provider_factory,
+            vec![exex_handle1, exex_handle2],
+            10,
+            Wal::new(temp_dir.path()).unwrap(),
+            empty_finalized_header_stream(),
+        );
+
+        let mut cx = Context::from_waker(futures::task::noop_waker_ref());
+
+        let mut pinned_manager = std::pin::pin!(exex_manager);
+
+        let _ = pinned_manager.as_mut().poll(&mut cx);
+
+        // Get the receiver for the finished height
+        let mut receiver = pinned_manager.handle.finished_height();
+
+        // Wait for a new value to be sent
+        receiver.changed().await.unwrap();
+
+        // Get the latest value
+        let finished_height = *receiver.borrow();
+
+        // The finished height should be updated to the lower block height
+        assert_eq!(finished_height, FinishedExExHeight::Height(10));
+    }
+
+    #[tokio::test]
+    async fn test_updates_block_height_greater() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        let wal = Wal::new(temp_dir.path()).unwrap();
+
+        let provider_factory = create_test_provider_factory();
+
+        // Create two `ExExHandle` instances
+        let (exex_handle1, event_tx1, _) =
+            ExExHandle::new("test_exex1".to_string(), Head::default(), (), (), wal.handle());
+        let (exex_handle2, event_tx2, _) =
+            ExExHandle::new("test_exex2".to_string(), Head::default(), (), (), wal.handle());
+
+        // Assert that the initial block height is `None` for the first `ExExHandle`.
+        assert!(exex_handle1.finished_height.is_none());
+
+        let block1 = BlockNumHash::new(42, B256::random());
+        let block2 = BlockNumHash::new(100, B256::random());
+
+        // Send events to update the block heights of the two handles, with the second being higher.
+        event_tx1.send(ExExEvent::FinishedHeight(block1)).unwrap();
+        event_tx2.send(ExExEvent::FinishedHeight(block2)).unwrap();
+
+        let exex_manager = ExExManager::new(
+            provider_factory,
+            vec![exex_handle1, exex_handle2],
+            10,
+            Wal::new(temp_dir.path()).unwrap(),
+            empty_finalized_header_stream(),
+        );
+
+        let mut cx = Context::from_waker(futures::task::noop_waker_ref());
+
+        let mut pinned_manager = std::pin::pin!(exex_manager);
+
+        let _ = pinned_manager.as_mut().poll(&mut cx);
+
+        // Get the receiver for the finished height
+        let mut receiver = pinned_manager.handle.finished_height();
+
+        // Wait for a new value to be sent
+        receiver.changed().await.unwrap();
+
+        // Get the latest value
+        let finished_height = *receiver.borrow();
+
+        // The finished height should be updated to the lower block height
+        assert_eq!(finished_height, FinishedExExHeight::Height(42));
+
+        // // The lower block height should be retained
+        // let updated_exex_handle = &pinned_manager.exex_handles[0];
+        // assert_eq!(updated_exex_handle.finished_height, Some(42));
+    }
+
+    #[tokio::test]
+    async fn test_exex_manager_capacity() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        let wal = Wal::new(temp_dir.path()).unwrap();
+
+        let provider_factory = create_test_provider_factory();
+
+        let (exex_handle_1, _, _) =
+            ExExHandle::new("test_exex_1".to_string(), Head::default(), (), (), wal.handle());
+
+        // Create an ExExManager with a small max capacity
+        let max_capacity = 2;
+        let mut exex_manager = ExExManager::new(
+            provider_factory,
+            vec![exex_handle_1],
+            max_capacity,
+            Wal::new(temp_dir.path()).unwrap(),
+            empty_finalized_header_stream(),
+        );
+
+        let mut cx = Context::from_waker(futures::task::noop_waker_ref());
+
+        // Setup a notification
+        let notification = ExExNotification::ChainCommitted {
+            new: Arc::new(Chain::new(
+                vec![Default::default()],
+                Default::default(),
+                Default::default(),
+            )),
+        };
+
+        // Send notifications to go over the max capacity
+        exex_manager.handle.exex_tx.send(notification.clone()).unwrap();
+        exex_manager.handle.exex_tx.send(notification.clone()).unwrap();
+        exex_manager.handle.exex_tx.send(notification).unwrap();
+
+        // Pin the ExExManager to call the poll method
+        let mut pinned_manager = std::pin::pin!(exex_manager);
+
+        // Before polling, the next notification ID should be 0 and the buffer should be empty
+        assert_eq!(pinned_manager.next_id, 0);
+        assert_eq!(pinned_manager.buffer.len(), 0);
+
+        let _ = pinned_manager.as_mut().poll(&mut cx);
+
+        // After polling, the next notification ID and buffer size should be updated
+        assert_eq!(pinned_manager.next_id, 2);
+        assert_eq!(pinned_manager.buffer.len(), 2);
+    }
+
+    #[tokio::test]
+    async fn exex_handle_new() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        let wal = Wal::new(temp_dir.path()).unwrap();
+
+        let (mut exex_handle, _, mut notifications) =
+            ExExHandle::new("test_exex".to_string(), Head::default(), (), (), wal.handle());
+
+        // Check initial state
+        assert_eq!(exex_handle.id, "test_exex");
+        assert_eq!(exex_handle.next_notification_id, 0);
+
+        // Setup two blocks for the chain commit notification
+        let mut block1 = SealedBlockWithSenders::default();
+        block1.block.header.set_hash(B256::new([0x01; 32]));
+        block1.block.header.set_block_number(10);
+
+        let mut block2 = SealedBlockWithSenders::default();
+        block2.block.header.set_hash(B256::new([0x02; 32]));
+        block2.block.header.set_block_number(11);
+
+        // Setup a notification
+        let notification = ExExNotification::ChainCommitted {
+            new: Arc::new(Chain::new(
+                vec![block1.clone(), block2.clone()],
+                Default::default(),
+                Default::default(),
+            )),
+        };
+
+        let mut cx = Context::from_waker(futures::task::noop_waker_ref());
+
+        // Send a notification and ensure it's received correctly
+        match exex_handle.send(&mut cx, &(22, notification.clone())) {
+            Poll::Ready(Ok(())) => {
+                let received_notification = notifications.next().await.unwrap();
+                assert_eq!(received_notification, notification);
+            }
+            Poll::Pending => panic!("Notification send is pending"),
+            Poll::Ready(Err(e)) => panic!("Failed to send notification: {:?}", e),
+        }
+
+        // Ensure the notification ID was incremented
+        assert_eq!(exex_handle.next_notification_id, 23);
+    }
+
+    #[tokio::test]
+    async fn test_notification_if_finished_height_gt_chain_tip() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        let wal = Wal::new(temp_dir.path()).unwrap();
+
+        let (mut exex_handle, _, mut notifications) =
+            ExExHandle::new("test_exex".to_string(), Head::default(), (), (), wal.handle());
+
+        // Set finished_height to a value higher than the block tip
+        exex_handle.finished_height = Some(BlockNumHash::new(15, B256::random()));
+
+        let mut block1 = SealedBlockWithSenders::default();
+        block1.block.header.set_hash(B256::new([0x01; 32]));
+        block1.block.header.set_block_number(10);
+
+        let notification = ExExNotification::ChainCommitted {
+            new: Arc::new(Chain::new(vec![block1.clone()], Default::default(), Default::default())),
+        };
+
+        let mut cx = Context::from_waker(futures::task::noop_waker_ref());
+
+        // Send the notification
+        match exex_handle.send(&mut cx, &(22, notification)) {
+            Poll::Ready(Ok(())) => {
+                poll_fn(|cx| {
+                    // The notification should be skipped, so nothing should be sent.
+                    // Check that the receiver channel is indeed empty
+                    assert_eq!(
+                        notifications.poll_next_unpin(cx),
+                        Poll::Pending,
+                        "Receiver channel should be empty"
+                    );
+                    Poll::Ready(())
+                })
+                .await;
+            }
+            Poll::Pending | Poll::Ready(Err(_)) => {
+                panic!("Notification should not be pending or fail");
+            }
+        }
+
+        // Ensure the notification ID was still incremented
+        assert_eq!(exex_handle.next_notification_id, 23);
+    }
+
+    #[tokio::test]
+    async fn test_sends_chain_reorged_notification() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        let wal = Wal::new(temp_dir.path()).unwrap();
+
+        let (mut exex_handle, _, mut notifications) =
+            ExExHandle::new("test_exex".to_string(), Head::default(), (), (), wal.handle());
+
+        let notification = ExExNotification::ChainReorged {
+            old: Arc::new(Chain::default()),
+            new: Arc::new(Chain::default()),
+        };
+
+        // Even if the finished height is higher than the tip of the new chain, the reorg
+        // notification should be received
+        exex_handle.finished_height = Some(BlockNumHash::new(u64::MAX, B256::random()));
+
+        let mut cx = Context::from_waker(futures::task::noop_waker_ref());
+
+        // Send the notification
+        match exex_handle.send(&mut cx, &(22, notification.clone())) {
+            Poll::Ready(Ok(())) => {
+                let received_notification = notifications.next().await.unwrap();
+                assert_eq!(received_notification, notification);
+            }
+            Poll::Pending | Poll::Ready(Err(_)) => {
+                panic!("Notification should not be pending or fail")
+            }
+        }
+
+        // Ensure the notification ID was incremented
+        assert_eq!(exex_handle.next_notification_id, 23);
+    }
+
+    #[tokio::test]
+    async fn test_sends_chain_reverted_notification() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        let wal = Wal::new(temp_dir.path()).unwrap();
+
+        let (mut exex_handle, _, mut notifications) =
+            ExExHandle::new("test_exex".to_string(), Head::default(), (), (), wal.handle());
+
+        let notification = ExExNotification::ChainReverted { old: Arc::new(Chain::default()) };
+
+        // Even if the finished height is higher than the tip of the new chain, the reorg
+        // notification should be received
+        exex_handle.finished_height = Some(BlockNumHash::new(u64::MAX, B256::random()));
+
+        let mut cx = Context::from_waker(futures::task::noop_waker_ref());
+
+        // Send the notification
+        match exex_handle.send(&mut cx, &(22, notification.clone())) {
+            Poll::Ready(Ok(())) => {
+                let received_notification = notifications.next().await.unwrap();
+                assert_eq!(received_notification, notification);
+            }
+            Poll::Pending | Poll::Ready(Err(_)) => {
+                panic!("Notification should not be pending or fail")
+            }
+        }
+
+        // Ensure the notification ID was incremented
+        assert_eq!(exex_handle.next_notification_id, 23);
+    }
+
+    #[tokio::test]
+    async fn test_replay_from() -> eyre::Result<()> {
+        let temp_dir = tempfile::tempdir().unwrap();
+        let wal = Wal::new(temp_dir.path()).unwrap();
+
+        let (exex_handle, events_tx, mut notifications) =
+            ExExHandle::new("test_exex".to_string(), Head::default(), (), (), wal.handle());
+        let replay_requests = exex_handle.replay_requests();
+
+        let mut exex_manager = std::pin::pin!(ExExManager::new(
+            create_test_provider_factory(),
+            vec![exex_handle],
+            10,
+            wal,
+            empty_finalized_header_stream(),
+        ));
+        let handle = exex_manager.handle();
+
+        let mut cx = Context::from_waker(futures::task::noop_waker_ref());
+
+        // The ExEx processes all notifications, so they're removed from the buffer
+        for number in 0..3 {
+            handle.send(committed_notification(number))?;
+            assert!(exex_manager.as_mut().poll(&mut cx)?.is_pending());
+            assert_eq!(
+                notifications.next().poll_unpin(&mut cx),
+                Poll::Ready(Some(committed_notification(number)))
+            );
+        }
+        events_tx.send(ExExEvent::FinishedHeight(BlockNumHash::new(2, B256::with_last_byte(2))))?;
+        assert!(exex_manager.as_mut().poll(&mut cx)?.is_pending());
+        assert!(exex_manager.buffer.is_empty());
+
+        // Replaying from a block re-delivers the notification containing it and all notifications
+        // after it from the WAL, and resets the finished height
+        let (tx, mut rx) = oneshot::channel();
+        replay_requests.send(ExExReplayRequest { from: 1, tx })?;
+        assert!(exex_manager.as_mut().poll(&mut cx)?.is_pending());
+        assert_eq!(rx.try_recv(), Ok(Ok(())));
+        assert!(exex_manager.exex_handles[0].finished_height.is_none());
+        for number in 1..3 {
+            assert_eq!(
+                next_notification(exex_manager.as_mut(), &mut notifications).await?,
+                Some(committed_notification(number))
+            );
+        }
+
+        // New notifications are delivered after the replayed ones
+        handle.send(committed_notification(3))?;
+        assert_eq!(
+            next_notification(exex_manager.as_mut(), &mut notifications).await?,
+            Some(committed_notification(3))
+        );
+        assert!(exex_manager.exex_handles[0].replay.is_none());
+
+        // Replaying from a block that is not in the WAL returns the earliest available block
+        let (tx, mut rx) = oneshot::channel();
+        replay_requests.send(ExExReplayRequest { from: 10, tx })?;
+        assert!(exex_manager.as_mut().poll(&mut cx)?.is_pending());
+        assert_eq!(
+            rx.try_recv(),
+            Ok(Err(ExExReplayError::NotAvailable { requested: 10, earliest_available: Some(0) }))
+        );
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_exex_lag() -> eyre::Result<()> {
+        let temp_dir = tempfile::tempdir().unwrap();
+        let wal = Wal::new(temp_dir.path()).unwrap();
+
+        let provider_factory = create_test_provider_factory();
+
+        let (exex_handle, events_tx, _notifications) =
+            ExExHandle::new("test_exex".to_string(), Head::default(), (), (), wal.handle());
+
+        let mut exex_manager = std::pin::pin!(ExExManager::new(
+            provider_factory,
+            vec![exex_handle],
+            10,
+            wal,
+            empty_finalized_header_stream(),
+        ));
+        let handle = exex_manager.handle();
+
+        let mut cx = Context::from_waker(futures::task::noop_waker_ref());
+
+        // No lag is reported before the first poll
+        assert_eq!(handle.lag("test_exex"), None);
+
+        let mut block1 = SealedBlockWithSenders::default();
+        block1.block.header.set_hash(B256::new([0x01; 32]));
+        block1.block.header.set_block_number(10);
+        let mut block2 = SealedBlockWithSenders::default();
+        block2.block.header.set_hash(B256::new([0x02; 32]));
+        block2.block.header.set_block_number(11);
+
+        for block in [block1.clone(), block2] {
+            handle.send(ExExNotification::ChainCommitted {
+                new: Arc::new(Chain::new(vec![block], Default::default(), Default::default())),
+            })?;
+        }
+        assert!(exex_manager.as_mut().poll(&mut cx)?.is_pending());
+
+        // Only one notification fits into the channel to the ExEx, and the ExEx has not emitted a
+        // `FinishedHeight` event yet
+        assert_eq!(
+            handle.lag("test_exex"),
+            Some(ExExLag { buffered_notifications: 1, blocks_behind: None })
+        );
+
+        events_tx.send(ExExEvent::FinishedHeight(block1.num_hash()))?;
+        assert!(exex_manager.as_mut().poll(&mut cx)?.is_pending());
+
+        assert_eq!(
+            handle.lag("test_exex"),
+            Some(ExExLag { buffered_notifications: 1, blocks_behind: Some(1) })
+        );
+        assert_eq!(handle.lag("unknown_exex"), None);
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_exex_drain() -> eyre::Result<()> {
+        let temp_dir = tempfile::tempdir().unwrap();
+        let wal = Wal::new(temp_dir.path()).unwrap();
+
+        let (exex_handle_1, events_tx_1, mut notifications_1) =
+            ExExHandle::new("test_exex_1".to_string(), Head::default(), (), (), wal.handle());
+        let (exex_handle_2, _events_tx_2, _notifications_2) =
+            ExExHandle::new("test_exex_2".to_string(), Head::default(), (), (), wal.handle());
+
+        let mut exex_manager = ExExManager::new(
+            create_test_provider_factory(),
+            vec![exex_handle_1, exex_handle_2],
+            10,
+            wal,
+            empty_finalized_header_stream(),
+        );
+        let handle = exex_manager.handle();
+
+        // Nothing to drain before any notifications are received
+        assert!(exex_manager.drain(Duration::from_millis(100)).await?.is_empty());
+
+        for number in [10, 11] {
+            let mut block = SealedBlockWithSenders::default();
+            block.block.header.set_block_number(number);
+            handle.send(ExExNotification::ChainCommitted {
+                new: Arc::new(Chain::new(vec![block], Default::default(), Default::default())),
+            })?;
+        }
+
+        // The first ExEx processes all notifications, while the second one never does
+        tokio::spawn(async move {
+            while let Some(notification) = notifications_1.next().await {
+                let tip = notification.committed_chain().unwrap().tip().num_hash();
+                events_tx_1.send(ExExEvent::FinishedHeight(tip)).unwrap();
+            }
+        });
 
-    /// Get the current capacity of the `ExEx` manager's internal notification buffer.
-    pub fn capacity(&self) -> usize {
-        self.current_capacity.load(Ordering::Relaxed)
-    }
+        assert_eq!(
+            exex_manager.drain(Duration::from_millis(100)).await?,
+            vec!["test_exex_2".to_string()]
+        );
+        assert_eq!(
+            exex_manager
+                .exex_handles
+                .iter()
+                .find(|exex| exex.id == "test_exex_1")
+                .and_then(|exex| exex.finished_height.map(|height| height.number)),
+            Some(11)
+        );
 
-    /// Whether there is capacity in the `ExEx` manager's internal notification buffer.
-    ///
-    /// If this returns `false`, the owner of the handle should **NOT** send new notifications over
-    /// the channel until the manager is ready again, as this can lead to unbounded memory growth.
-    pub fn has_capacity(&self) -> bool {
-        self.capacity() > 0
+        Ok(())
     }
 
-    /// Returns `true` if there are `ExEx`'s installed in the node.
-    pub const fn has_exexs(&self) -> bool {
-        self.num_exexs > 0
-    }
+    #[tokio::test]
+    async fn test_register_exex() -> eyre::Result<()> {
+        let temp_dir = tempfile::tempdir().unwrap();
+        let wal = Wal::new(temp_dir.path()).unwrap();
 
-    /// The finished height of all `ExEx`'s.
-    pub fn finished_height(&self) -> watch::Receiver<FinishedExExHeight> {
-        self.finished_height.clone()
-    }
+        let (exex_handle_1, events_tx_1, mut notifications_1) =
+            ExExHandle::new("test_exex_1".to_string(), Head::default(), (), (), wal.handle());
 
-    /// Wait until the manager is ready for new notifications.
-    pub async fn ready(&mut self) {
-        poll_fn(|cx| self.poll_ready(cx)).await
-    }
+        let mut exex_manager = std::pin::pin!(ExExManager::new(
+            create_test_provider_factory(),
+            vec![exex_handle_1],
+            10,
+            wal.clone(),
+            empty_finalized_header_stream(),
+        ));
+        let handle = exex_manager.handle();
 
-    /// Wait until the manager is ready for new notifications.
-    pub fn poll_ready(&mut self, cx: &mut Context<'_>) -> Poll<()> {
-        let rx = ready!(self.is_ready.poll(cx));
-        self.is_ready.set(make_wait_future(rx));
-        Poll::Ready(())
-    }
-}
+        let mut cx = Context::from_waker(futures::task::noop_waker_ref());
 
-/// Creates a future that resolves once the given watch channel receiver is true.
-async fn make_wait_future(mut rx: watch::Receiver<bool>) -> watch::Receiver<bool> {
-    // NOTE(onbjerg): We can ignore the error here, because if the channel is closed, the node
-    // is shutting down.
-    let _ = rx.wait_for(|ready| *ready).await;
-    rx
-}
+        // The first ExEx processes the first notification
+        handle.send(committed_notification(0))?;
+        assert!(exex_manager.as_mut().poll(&mut cx)?.is_pending());
+        let notification = notifications_1.next().poll_unpin(&mut cx);
+        assert_eq!(notification, Poll::Ready(Some(committed_notification(0))));
+        events_tx_1.send(ExExEvent::FinishedHeight(BlockNumHash::new(0, B256::ZERO)))?;
+        assert!(exex_manager.as_mut().poll(&mut cx)?.is_pending());
+        assert_eq!(*handle.finished_height().borrow(), FinishedExExHeight::Height(0));
 
-impl Clone for ExExManagerHandle {
-    fn clone(&self) -> Self {
-        Self {
-            exex_tx: self.exex_tx.clone(),
-            num_exexs: self.num_exexs,
-            is_ready_receiver: self.is_ready_receiver.clone(),
-            is_ready: ReusableBoxFuture::new(make_wait_future(self.is_ready_receiver.clone())),
-            current_capacity: self.current_capacity.clone(),
-            finished_height: self.finished_height.clone(),
-        }
-    }
-}
+        // Register the second ExEx through the handle
+        let (exex_handle_2, _, mut notifications_2) =
+            ExExHandle::new("test_exex_2".to_string(), Head::default(), (), (), wal.handle());
+        let mut register = Box::pin(handle.register_exex(exex_handle_2));
+        assert!(register.poll_unpin(&mut cx).is_pending());
+        assert!(exex_manager.as_mut().poll(&mut cx)?.is_pending());
+        assert_eq!(register.poll_unpin(&mut cx), Poll::Ready(Ok(())));
 
-#[cfg(test)]
-mod tests {
-    use super::*;
-    use alloy_primitives::B256;
-    use eyre::OptionExt;
-    use futures::{FutureExt, StreamExt};
-    use rand::Rng;
-    use reth_primitives::SealedBlockWithSenders;
-    use reth_provider::{test_utils::create_test_provider_factory, BlockWriter, Chain};
-    use reth_testing_utils::generators::{self, random_block};
+        // The finished height is not ready until the new ExEx emits its finished height
+        assert_eq!(*handle.finished_height().borrow(), FinishedExExHeight::NotReady);
+        assert_eq!(exex_manager.exex_handles.len(), 2);
 
-    fn empty_finalized_header_stream() -> ForkChoiceStream<SealedHeader> {
-        let (tx, rx) = watch::channel(None);
-        // Do not drop the sender, otherwise the receiver will always return an error
-        std::mem::forget(tx);
-        ForkChoiceStream::new(rx)
+        // Both ExExes receive new notifications
+        handle.send(committed_notification(1))?;
+        assert!(exex_manager.as_mut().poll(&mut cx)?.is_pending());
+        assert_eq!(
+            notifications_1.next().poll_unpin(&mut cx),
+            Poll::Ready(Some(committed_notification(1)))
+        );
+        assert_eq!(
+            notifications_2.next().poll_unpin(&mut cx),
+            Poll::Ready(Some(committed_notification(1)))
+        );
+
+        // An ExEx with the same ID can't be registered again
+        let (exex_handle_2, _, _) =
+            ExExHandle::new("test_exex_2".to_string(), Head::default(), (), (), wal.handle());
+        assert_eq!(
+            exex_manager.register_exex(exex_handle_2),
+            Err(ExExRegistrationError::DuplicateId("test_exex_2".to_string()))
+        );
+
+        Ok(())
     }
 
     #[tokio::test]
-    async fn test_delivers_events() {
+    async fn test_exex_closed_channel() -> eyre::Result<()> {
         let temp_dir = tempfile::tempdir().unwrap();
         let wal = Wal::new(temp_dir.path()).unwrap();
 
-        let (mut exex_handle, event_tx, mut _notification_rx) =
+        let (exex_handle, _, notifications) =
             ExExHandle::new("test_exex".to_string(), Head::default(), (), (), wal.handle());
 
-        // Send an event and check that it's delivered correctly
-        let event = ExExEvent::FinishedHeight(BlockNumHash::new(42, B256::random()));
-        event_tx.send(event).unwrap();
-        let received_event = exex_handle.receiver.recv().await.unwrap();
-        assert_eq!(received_event, event);
+        let mut exex_manager = std::pin::pin!(ExExManager::new(
+            create_test_provider_factory(),
+            vec![exex_handle],
+            10,
+            wal,
+            empty_finalized_header_stream(),
+        ));
+        let handle = exex_manager.handle();
+
+        let mut cx = Context::from_waker(futures::task::noop_waker_ref());
+
+        // The ExEx exits while the manager is running, which fails the manager
+        handle.send(committed_notification(0))?;
+        drop(notifications);
+        assert!(matches!(exex_manager.as_mut().poll(&mut cx), Poll::Ready(Err(_))));
+
+        Ok(())
     }
 
     #[tokio::test]
-    async fn test_has_exexs() {
+    async fn test_register_exex_after_all_removed() -> eyre::Result<()> {
         let temp_dir = tempfile::tempdir().unwrap();
         let wal = Wal::new(temp_dir.path()).unwrap();
 
-        let (exex_handle_1, _, _) =
+        let (exex_handle_1, _, notifications_1) =
             ExExHandle::new("test_exex_1".to_string(), Head::default(), (), (), wal.handle());
 
-        assert!(!ExExManager::new((), vec![], 0, wal.clone(), empty_finalized_header_stream())
-            .handle
-            .has_exexs());
+        let mut exex_manager = std::pin::pin!(ExExManager::new(
+            create_test_provider_factory(),
+            vec![exex_handle_1],
+            10,
+            wal.clone(),
+            empty_finalized_header_stream(),
+        )
+        .with_isolated_failures());
+        let handle = exex_manager.handle();
 
-        assert!(ExExManager::new((), vec![exex_handle_1], 0, wal, empty_finalized_header_stream())
-            .handle
-            .has_exexs());
+        let mut cx = Context::from_waker(futures::task::noop_waker_ref());
+
+        // The only ExEx exits, and is removed with a notification still buffered for it
+        handle.send(committed_notification(0))?;
+        drop(notifications_1);
+        assert!(exex_manager.as_mut().poll(&mut cx)?.is_pending());
+        assert!(exex_manager.exex_handles.is_empty());
+        assert!(exex_manager.buffer.is_empty());
+        assert_eq!(exex_manager.min_id, exex_manager.next_id);
+
+        // A new ExEx is registered and receives the next notifications
+        let (exex_handle_2, _, mut notifications_2) =
+            ExExHandle::new("test_exex_2".to_string(), Head::default(), (), (), wal.handle());
+        exex_manager.register_exex(exex_handle_2)?;
+        handle.send(committed_notification(1))?;
+        assert!(exex_manager.as_mut().poll(&mut cx)?.is_pending());
+        assert_eq!(
+            notifications_2.next().poll_unpin(&mut cx),
+            Poll::Ready(Some(committed_notification(1)))
+        );
+
+        Ok(())
     }
 
     #[tokio::test]
-    async fn test_has_capacity() {
+    async fn test_exex_drain_exited() -> eyre::Result<()> {
         let temp_dir = tempfile::tempdir().unwrap();
         let wal = Wal::new(temp_dir.path()).unwrap();
 
-        let (exex_handle_1, _, _) =
+        let (exex_handle_1, events_tx_1, mut notifications_1) =
             ExExHandle::new("test_exex_1".to_string(), Head::default(), (), (), wal.handle());
+        let (exex_handle_2, events_tx_2, mut notifications_2) =
+            ExExHandle::new("test_exex_2".to_string(), Head::default(), (), (), wal.handle());
 
-        assert!(!ExExManager::new((), vec![], 0, wal.clone(), empty_finalized_header_stream())
-            .handle
-            .has_capacity());
-
-        assert!(ExExManager::new(
-            (),
-            vec![exex_handle_1],
+        let mut exex_manager = ExExManager::new(
+            create_test_provider_factory(),
+            vec![exex_handle_1, exex_handle_2],
             10,
             wal,
-            empty_finalized_header_stream()
-        )
-        .handle
-        .has_capacity());
+            empty_finalized_header_stream(),
+        );
+        let handle = exex_manager.handle();
+
+        for number in 0..3 {
+            handle.send(committed_notification(number))?;
+        }
+
+        // The first ExEx processes all notifications
+        tokio::spawn(async move {
+            while let Some(notification) = notifications_1.next().await {
+                let tip = notification.committed_chain().unwrap().tip().num_hash();
+                events_tx_1.send(ExExEvent::FinishedHeight(tip)).unwrap();
+            }
+        });
+        // The second ExEx exits after processing the first notification, dropping its
+        // notifications while the next ones are still buffered for it
+        tokio::spawn(async move {
+            let notification = notifications_2.next().await.unwrap();
+            let tip = notification.committed_chain().unwrap().tip().num_hash();
+            events_tx_2.send(ExExEvent::FinishedHeight(tip)).unwrap();
+        });
+
+        // The exited ExEx is skipped instead of failing the drain
+        assert!(exex_manager.drain(Duration::from_millis(100)).await?.is_empty());
+        assert!(exex_manager.buffer.is_empty());
+        assert_eq!(
+            exex_manager.exex_statuses(),
+            vec![
+                ExExStatus {
+                    id: "test_exex_1".to_string(),
+                    finished_height: Some(BlockNumHash::new(2, B256::with_last_byte(2))),
+                    state: ExExState::Running
+                },
+                ExExStatus {
+                    id: "test_exex_2".to_string(),
+                    finished_height: Some(BlockNumHash::new(0, B256::with_last_byte(0))),
+                    state: ExExState::Exited
+                },
+            ]
+        );
+
+        Ok(())
     }
 
-    #[test]
-    fn test_push_notification() {
+    #[tokio::test]
+    async fn test_unregister_exex() -> eyre::Result<()> {
         let temp_dir = tempfile::tempdir().unwrap();
         let wal = Wal::new(temp_dir.path()).unwrap();
 
-        let (exex_handle, _, _) =
-            ExExHandle::new("test_exex".to_string(), Head::default(), (), (), wal.handle());
-
-        // Create a mock ExExManager and add the exex_handle to it
-        let mut exex_manager =
-            ExExManager::new((), vec![exex_handle], 10, wal, empty_finalized_header_stream());
-
-        // Define the notification for testing
-        let mut block1 = SealedBlockWithSenders::default();
-        block1.block.header.set_hash(B256::new([0x01; 32]));
-        block1.block.header.set_block_number(10);
+        let (exex_handle_1, _, mut notifications_1) =
+            ExExHandle::new("test_exex_1".to_string(), Head::default(), (), (), wal.handle());
+        let (exex_handle_2, events_tx_2, mut notifications_2) =
+            ExExHandle::new("test_exex_2".to_string(), Head::default(), (), (), wal.handle());
+        let stop_signal_2 = exex_handle_2.stop_signal();
 
-        let notification1 = ExExNotification::ChainCommitted {
-            new: Arc::new(Chain::new(vec![block1.clone()], Default::default(), Default::default())),
-        };
+        let mut exex_manager = std::pin::pin!(ExExManager::new(
+            create_test_provider_factory(),
+            vec![exex_handle_1, exex_handle_2],
+            10,
+            wal,
+            empty_finalized_header_stream(),
+        ));
+        let handle = exex_manager.handle();
 
-        // Push the first notification
-        exex_manager.push_notification(notification1.clone());
+        let mut cx = Context::from_waker(futures::task::noop_waker_ref());
 
-        // Verify the buffer contains the notification with the correct ID
+        // The first ExEx processes all notifications. The second one already finished the first
+        // notification, so it's skipped, and receives the second one but never consumes it, so
+        // the last notification stays buffered for it
+        for number in 0..3 {
+            handle.send(committed_notification(number))?;
+        }
+        events_tx_2.send(ExExEvent::FinishedHeight(BlockNumHash::new(0, B256::ZERO)))?;
+        for number in 0..3 {
+            assert!(exex_manager.as_mut().poll(&mut cx)?.is_pending());
+            assert_eq!(
+                notifications_1.next().poll_unpin(&mut cx),
+                Poll::Ready(Some(committed_notification(number)))
+            );
+        }
         assert_eq!(exex_manager.buffer.len(), 1);
-        assert_eq!(exex_manager.buffer.front().unwrap().0, 0);
-        assert_eq!(exex_manager.buffer.front().unwrap().1, notification1);
-        assert_eq!(exex_manager.next_id, 1);
 
-        // Push another notification
-        let mut block2 = SealedBlockWithSenders::default();
-        block2.block.header.set_hash(B256::new([0x02; 32]));
-        block2.block.header.set_block_number(20);
+        // Unregister the second ExEx through the handle, which signals it to stop and waits for
+        // its final finished height
+        let mut unregister =
+            Box::pin(handle.unregister_exex("test_exex_2", DEFAULT_EXEX_UNREGISTER_TIMEOUT));
+        assert!(unregister.poll_unpin(&mut cx).is_pending());
+        assert!(exex_manager.as_mut().poll(&mut cx)?.is_pending());
+        assert!(stop_signal_2.is_cancelled());
+        assert!(unregister.poll_unpin(&mut cx).is_pending());
 
-        let notification2 = ExExNotification::ChainCommitted {
-            new: Arc::new(Chain::new(vec![block2.clone()], Default::default(), Default::default())),
-        };
+        let final_height = BlockNumHash::new(1, B256::with_last_byte(1));
+        events_tx_2.send(ExExEvent::FinishedHeight(final_height))?;
+        assert!(exex_manager.as_mut().poll(&mut cx)?.is_pending());
+        assert_eq!(
+            unregister.poll_unpin(&mut cx),
+            Poll::Ready(Ok(ExExStatus {
+                id: "test_exex_2".to_string(),
+                finished_height: Some(final_height),
+                state: ExExState::Terminated
+            }))
+        );
 
-        exex_manager.push_notification(notification2.clone());
+        // The notifications buffered for the unregistered ExEx are released, and its notifications
+        // stream ends after the notification it already received
+        assert!(exex_manager.buffer.is_empty());
+        assert_eq!(
+            notifications_2.next().poll_unpin(&mut cx),
+            Poll::Ready(Some(committed_notification(1)))
+        );
+        assert_eq!(notifications_2.next().poll_unpin(&mut cx), Poll::Ready(None));
+        assert_eq!(
+            *exex_manager.handle().statuses().borrow(),
+            vec![ExExStatus {
+                id: "test_exex_1".to_string(),
+                finished_height: None,
+                state: ExExState::Running
+            }]
+        );
 
-        // Verify the buffer contains both notifications with correct IDs
-        assert_eq!(exex_manager.buffer.len(), 2);
-        assert_eq!(exex_manager.buffer.front().unwrap().0, 0);
-        assert_eq!(exex_manager.buffer.front().unwrap().1, notification1);
-        assert_eq!(exex_manager.buffer.get(1).unwrap().0, 1);
-        assert_eq!(exex_manager.buffer.get(1).unwrap().1, notification2);
-        assert_eq!(exex_manager.next_id, 2);
+        // Unregistering the last ExEx leaves no ExExes to wait for
+        assert!(exex_manager.unregister_exex("test_exex_1").is_ok());
+        assert!(exex_manager.as_mut().poll(&mut cx)?.is_pending());
+        assert_eq!(*handle.finished_height().borrow(), FinishedExExHeight::NoExExs);
+        assert!(!handle.has_exexs());
+        assert_eq!(
+            exex_manager.unregister_exex("test_exex_1"),
+            Err(ExExRegistrationError::NotFound("test_exex_1".to_string()))
+        );
+
+        Ok(())
     }
 
-    #[test]
-    fn test_update_capacity() {
+    #[tokio::test]
+    async fn test_unregister_running_exex() -> eyre::Result<()> {
         let temp_dir = tempfile::tempdir().unwrap();
         let wal = Wal::new(temp_dir.path()).unwrap();
 
-        let (exex_handle, _, _) =
-            ExExHandle::new("test_exex".to_string(), Head::default(), (), (), wal.handle());
-
-        // Create a mock ExExManager and add the exex_handle to it
-        let max_capacity = 5;
-        let mut exex_manager = ExExManager::new(
-            (),
-            vec![exex_handle],
-            max_capacity,
-            wal,
-            empty_finalized_header_stream(),
-        );
-
-        // Push some notifications to fill part of the buffer
-        let mut block1 = SealedBlockWithSenders::default();
-        block1.block.header.set_hash(B256::new([0x01; 32]));
-        block1.block.header.set_block_number(10);
+        let (exex_handle_1, events_tx_1, mut notifications_1) =
+            ExExHandle::new("test_exex_1".to_string(), Head::default(), (), (), wal.handle());
+        let (exex_handle_2, events_tx_2, mut notifications_2) =
+            ExExHandle::new("test_exex_2".to_string(), Head::default(), (), (), wal.handle());
+        let stop_signal_2 = exex_handle_2.stop_signal();
 
-        let notification1 = ExExNotification::ChainCommitted {
-            new: Arc::new(Chain::new(vec![block1.clone()], Default::default(), Default::default())),
-        };
+        let exex_manager = ExExManager::new(
+            create_test_provider_factory(),
+            vec![exex_handle_1, exex_handle_2],
+            10,
+            wal,
+            empty_finalized_header_stream(),
+        );
+        let handle = exex_manager.handle();
+        let exex_manager = tokio::spawn(exex_manager);
+
+        // The second ExEx processes notifications until it's signaled to stop, and then returns
+        let exex_2 = tokio::spawn(async move {
+            loop {
+                tokio::select! {
+                    _ = stop_signal_2.cancelled() => break,
+                    Some(notification) = notifications_2.next() => {
+                        let tip = notification.committed_chain().unwrap().tip().num_hash();
+                        events_tx_2.send(ExExEvent::FinishedHeight(tip)).unwrap();
+                    }
+                }
+            }
+        });
 
-        exex_manager.push_notification(notification1.clone());
-        exex_manager.push_notification(notification1);
+        handle.send(committed_notification(0))?;
+        assert_eq!(notifications_1.next().await, Some(committed_notification(0)));
+        events_tx_1
+            .send(ExExEvent::FinishedHeight(BlockNumHash::new(0, B256::with_last_byte(0))))?;
+        handle
+            .finished_height()
+            .wait_for(|height| *height == FinishedExExHeight::Height(0))
+            .await?;
 
-        // Update capacity
-        exex_manager.update_capacity();
+        // Unregistering the second ExEx waits for it to return
+        assert_eq!(
+            handle.unregister_exex("test_exex_2", DEFAULT_EXEX_UNREGISTER_TIMEOUT).await,
+            Ok(ExExStatus {
+                id: "test_exex_2".to_string(),
+                finished_height: Some(BlockNumHash::new(0, B256::with_last_byte(0))),
+                state: ExExState::Terminated
+            })
+        );
+        exex_2.await?;
 
-        // Verify current capacity and metrics
-        assert_eq!(exex_manager.current_capacity.load(Ordering::Relaxed), max_capacity - 2);
+        // The manager keeps running and delivering notifications to the first ExEx
+        handle.send(committed_notification(1))?;
+        assert_eq!(notifications_1.next().await, Some(committed_notification(1)));
+        assert!(!exex_manager.is_finished());
 
-        // Clear the buffer and update capacity
-        exex_manager.buffer.clear();
-        exex_manager.update_capacity();
+        // An ExEx that doesn't stop is unregistered after the timeout
+        assert_eq!(
+            handle.unregister_exex("test_exex_1", Duration::from_millis(10)).await,
+            Ok(ExExStatus {
+                id: "test_exex_1".to_string(),
+                finished_height: Some(BlockNumHash::new(0, B256::with_last_byte(0))),
+                state: ExExState::Terminated
+            })
+        );
+        assert_eq!(*handle.finished_height().borrow(), FinishedExExHeight::NoExExs);
+        assert!(!exex_manager.is_finished());
 
-        // Verify current capacity
-        assert_eq!(exex_manager.current_capacity.load(Ordering::Relaxed), max_capacity);
+        Ok(())
     }
 
     #[tokio::test]
-    async fn test_updates_block_height() {
+    async fn test_report_exex_error() -> eyre::Result<()> {
         let temp_dir = tempfile::tempdir().unwrap();
         let wal = Wal::new(temp_dir.path()).unwrap();
 
-        let provider_factory = create_test_provider_factory();
-
-        let (exex_handle, event_tx, mut _notification_rx) =
-            ExExHandle::new("test_exex".to_string(), Head::default(), (), (), wal.handle());
-
-        // Check initial block height
-        assert!(exex_handle.finished_height.is_none());
-
-        // Update the block height via an event
-        let block = BlockNumHash::new(42, B256::random());
-        event_tx.send(ExExEvent::FinishedHeight(block)).unwrap();
+        let (exex_handle_1, events_tx_1, _notifications_1) =
+            ExExHandle::new("test_exex_1".to_string(), Head::default(), (), (), wal.handle());
+        let stop_signal_1 = exex_handle_1.stop_signal();
+        let (exex_handle_2, _, notifications_2) =
+            ExExHandle::new("test_exex_2".to_string(), Head::default(), (), (), wal.handle());
 
-        // Create a mock ExExManager and add the exex_handle to it
-        let exex_manager = ExExManager::new(
-            provider_factory,
-            vec![exex_handle],
+        let mut exex_manager = std::pin::pin!(ExExManager::new(
+            create_test_provider_factory(),
+            vec![exex_handle_1, exex_handle_2],
             10,
-            Wal::new(temp_dir.path()).unwrap(),
+            wal,
             empty_finalized_header_stream(),
-        );
+        )
+        .with_isolated_failures());
+        let handle = exex_manager.handle();
 
         let mut cx = Context::from_waker(futures::task::noop_waker_ref());
 
-        // Pin the ExExManager to call the poll method
-        let mut pinned_manager = std::pin::pin!(exex_manager);
-        let _ = pinned_manager.as_mut().poll(&mut cx);
-
-        // Check that the block height was updated
-        let updated_exex_handle = &pinned_manager.exex_handles[0];
-        assert_eq!(updated_exex_handle.finished_height, Some(block));
+        // The first ExEx returns an error while still registered, so it's removed and signaled to
+        // stop
+        let height = BlockNumHash::new(0, B256::with_last_byte(0));
+        events_tx_1.send(ExExEvent::FinishedHeight(height))?;
+        handle.report_exex_error("test_exex_1", "first error".to_string());
+        assert!(exex_manager.as_mut().poll(&mut cx)?.is_pending());
+        assert!(stop_signal_1.is_cancelled());
+        assert_eq!(exex_manager.exex_handles.len(), 1);
 
-        // Get the receiver for the finished height
-        let mut receiver = pinned_manager.handle.finished_height();
+        // The second ExEx closes its notifications channel, so it's removed and reported as
+        // errored
+        drop(notifications_2);
+        handle.send(committed_notification(0))?;
+        assert!(exex_manager.as_mut().poll(&mut cx)?.is_pending());
+        assert!(exex_manager.exex_handles.is_empty());
+        assert_eq!(
+            handle.statuses().borrow().last(),
+            Some(&ExExStatus {
+                id: "test_exex_2".to_string(),
+                finished_height: None,
+                state: ExExState::Errored("notifications channel closed".to_string())
+            })
+        );
 
-        // Wait for a new value to be sent
-        receiver.changed().await.unwrap();
+        // The second ExEx then returns an error, which replaces the reason of its status
+        handle.report_exex_error("test_exex_2", "second error".to_string());
+        assert!(exex_manager.as_mut().poll(&mut cx)?.is_pending());
 
-        // Get the latest value
-        let finished_height = *receiver.borrow();
+        assert!(exex_manager.exex_handles.is_empty());
+        assert_eq!(
+            *handle.statuses().borrow(),
+            vec![
+                ExExStatus {
+                    id: "test_exex_1".to_string(),
+                    finished_height: Some(height),
+                    state: ExExState::Errored("first error".to_string())
+                },
+                ExExStatus {
+                    id: "test_exex_2".to_string(),
+                    finished_height: None,
+                    state: ExExState::Errored("second error".to_string())
+                },
+            ]
+        );
 
-        // The finished height should be updated to the lower block height
-        assert_eq!(finished_height, FinishedExExHeight::Height(42));
+        Ok(())
     }
 
     #[tokio::test]
-    async fn test_updates_block_height_lower() {
+    async fn test_exex_statuses() -> eyre::Result<()> {
         let temp_dir = tempfile::tempdir().unwrap();
         let wal = Wal::new(temp_dir.path()).unwrap();
 
-        let provider_factory = create_test_provider_factory();
-
-        // Create two `ExExHandle` instances
-        let (exex_handle1, event_tx1, _) =
-            ExExHandle::new("test_exex1".to_string(), Head::default(), (), (), wal.handle());
-        let (exex_handle2, event_tx2, _) =
-            ExExHandle::new("test_exex2".to_string(), Head::default(), (), (), wal.handle());
-
-        let block1 = BlockNumHash::new(42, B256::random());
-        let block2 = BlockNumHash::new(10, B256::random());
-
-        // Send events to update the block heights of the two handles, with the second being lower
-        event_tx1.send(ExExEvent::FinishedHeight(block1)).unwrap();
-        event_tx2.send(ExExEvent::FinishedHeight(block2)).unwrap();
+        let (exex_handle, events_tx, notifications) =
+            ExExHandle::new("test_exex".to_string(), Head::default(), (), (), wal.handle());
 
-        let exex_manager = ExExManager::new(
-            provider_factory,
-            vec![exex_handle1, exex_handle2],
+        let mut exex_manager = std::pin::pin!(ExExManager::new(
+            create_test_provider_factory(),
+            vec![exex_handle],
             10,
-            Wal::new(temp_dir.path()).unwrap(),
+            wal,
             empty_finalized_header_stream(),
-        );
+        ));
+        let statuses = exex_manager.handle().statuses();
 
         let mut cx = Context::from_waker(futures::task::noop_waker_ref());
 
-        let mut pinned_manager = std::pin::pin!(exex_manager);
-
-        let _ = pinned_manager.as_mut().poll(&mut cx);
-
-        // Get the receiver for the finished height
-        let mut receiver = pinned_manager.handle.finished_height();
+        assert_eq!(
+            *statuses.borrow(),
+            vec![ExExStatus {
+                id: "test_exex".to_string(),
+                finished_height: None,
+                state: ExExState::Running
+            }]
+        );
 
-        // Wait for a new value to be sent
-        receiver.changed().await.unwrap();
+        let finished_height = BlockNumHash::new(42, B256::random());
+        events_tx.send(ExExEvent::FinishedHeight(finished_height))?;
+        // The ExEx exits, dropping its notifications stream
+        drop(notifications);
+        assert!(exex_manager.as_mut().poll(&mut cx)?.is_pending());
 
-        // Get the latest value
-        let finished_height = *receiver.borrow();
+        assert_eq!(
+            exex_manager.exex_statuses(),
+            vec![ExExStatus {
+                id: "test_exex".to_string(),
+                finished_height: Some(finished_height),
+                state: ExExState::Exited
+            }]
+        );
+        assert_eq!(*statuses.borrow(), exex_manager.exex_statuses());
 
-        // The finished height should be updated to the lower block height
-        assert_eq!(finished_height, FinishedExExHeight::Height(10));
+        Ok(())
     }
 
     #[tokio::test]
-    async fn test_updates_block_height_greater() {
+    async fn test_exex_wal_replay() -> eyre::Result<()> {
+        let mut rng = generators::rng();
+
         let temp_dir = tempfile::tempdir().unwrap();
-        let wal = Wal::new(temp_dir.path()).unwrap();
+        let wal_path = temp_dir.path().join("wal");
+        let finished_heights_path = temp_dir.path().join("finished_heights");
 
         let provider_factory = create_test_provider_factory();
 
-        // Create two `ExExHandle` instances
-        let (exex_handle1, event_tx1, _) =
-            ExExHandle::new("test_exex1".to_string(), Head::default(), (), (), wal.handle());
-        let (exex_handle2, event_tx2, _) =
-            ExExHandle::new("test_exex2".to_string(), Head::default(), (), (), wal.handle());
+        let block_1 = random_block(&mut rng, 1, Default::default())
+            .seal_with_senders()
+            .ok_or_eyre("failed to recover senders")?;
+        let block_2 = random_block(&mut rng, 2, Default::default())
+            .seal_with_senders()
+            .ok_or_eyre("failed to recover senders")?;
+        let notification_1 = ExExNotification::ChainCommitted {
+            new: Arc::new(Chain::new(vec![block_1.clone()], Default::default(), None)),
+        };
+        let notification_2 = ExExNotification::ChainCommitted {
+            new: Arc::new(Chain::new(vec![block_2], Default::default(), None)),
+        };
 
-        // Assert that the initial block height is `None` for the first `ExExHandle`.
-        assert!(exex_handle1.finished_height.is_none());
+        let mut cx = Context::from_waker(futures::task::noop_waker_ref());
 
-        let block1 = BlockNumHash::new(42, B256::random());
-        let block2 = BlockNumHash::new(100, B256::random());
+        {
+            let wal = Wal::new(&wal_path)?;
+            let (exex_handle_1, events_tx_1, mut notifications_1) =
+                ExExHandle::new("test_exex_1".to_string(), Head::default(), (), (), wal.handle());
+            let (exex_handle_2, _, _notifications_2) =
+                ExExHandle::new("test_exex_2".to_string(), Head::default(), (), (), wal.handle());
+            let mut exex_manager = std::pin::pin!(ExExManager::new(
+                provider_factory.clone(),
+                vec![exex_handle_1, exex_handle_2],
+                10,
+                wal,
+                empty_finalized_header_stream(),
+            )
+            .with_finished_heights_storage(FinishedHeightsStorage::new(&finished_heights_path)?)?
+            .with_wal_replay()?);
+
+            exex_manager.handle().send(notification_1.clone())?;
+            exex_manager.handle().send(notification_2.clone())?;
+            assert!(exex_manager.as_mut().poll(&mut cx)?.is_pending());
+
+            // The first ExEx processes only the first notification, and the second ExEx doesn't
+            // process anything
+            assert_eq!(
+                notifications_1.next().poll_unpin(&mut cx),
+                Poll::Ready(Some(notification_1.clone()))
+            );
+            events_tx_1.send(ExExEvent::FinishedHeight(block_1.num_hash()))?;
+            assert!(exex_manager.as_mut().poll(&mut cx)?.is_pending());
 
-        // Send events to update the block heights of the two handles, with the second being higher.
-        event_tx1.send(ExExEvent::FinishedHeight(block1)).unwrap();
-        event_tx2.send(ExExEvent::FinishedHeight(block2)).unwrap();
+            // Wait for the finished heights to be persisted in the background
+            poll_fn(|cx| exex_manager.poll_persist_finished_heights(cx)).await;
 
-        let exex_manager = ExExManager::new(
+            // The node crashes after both notifications were committed to the WAL, but before the
+            // ExExes consumed them
+        }
+
+        let wal = Wal::new(&wal_path)?;
+        let (exex_handle_1, _, mut notifications_1) =
+            ExExHandle::new("test_exex_1".to_string(), Head::default(), (), (), wal.handle());
+        let (exex_handle_2, _, mut notifications_2) =
+            ExExHandle::new("test_exex_2".to_string(), Head::default(), (), (), wal.handle());
+        let mut exex_manager = std::pin::pin!(ExExManager::new(
             provider_factory,
-            vec![exex_handle1, exex_handle2],
+            vec![exex_handle_1, exex_handle_2],
             10,
-            Wal::new(temp_dir.path()).unwrap(),
+            wal,
             empty_finalized_header_stream(),
-        );
+        )
+        .with_finished_heights_storage(FinishedHeightsStorage::new(&finished_heights_path)?)?
+        .with_wal_replay()?);
 
-        let mut cx = Context::from_waker(futures::task::noop_waker_ref());
+        // The replayed notifications are not buffered, so they don't take up the capacity
+        assert!(exex_manager.buffer.is_empty());
+        assert_eq!(exex_manager.handle().capacity(), 10);
+        assert_eq!(*exex_manager.head.borrow(), Some(2));
 
-        let mut pinned_manager = std::pin::pin!(exex_manager);
+        // The first ExEx receives only the notification it didn't process
+        assert_eq!(
+            next_notification(exex_manager.as_mut(), &mut notifications_1).await?,
+            Some(notification_2.clone())
+        );
 
-        let _ = pinned_manager.as_mut().poll(&mut cx);
+        // The second ExEx receives all notifications
+        assert_eq!(
+            next_notification(exex_manager.as_mut(), &mut notifications_2).await?,
+            Some(notification_1)
+        );
+        assert_eq!(
+            next_notification(exex_manager.as_mut(), &mut notifications_2).await?,
+            Some(notification_2)
+        );
 
-        // Get the receiver for the finished height
-        let mut receiver = pinned_manager.handle.finished_height();
+        assert!(exex_manager.as_mut().poll(&mut cx)?.is_pending());
+        assert!(notifications_1.next().poll_unpin(&mut cx).is_pending());
+        assert!(notifications_2.next().poll_unpin(&mut cx).is_pending());
 
-        // Wait for a new value to be sent
-        receiver.changed().await.unwrap();
+        // Replayed notifications are not committed to the WAL again
+        assert_eq!(exex_manager.wal.iter_notifications()?.count(), 2);
 
-        // Get the latest value
-        let finished_height = *receiver.borrow();
+        Ok(())
+    }
 
-        // The finished height should be updated to the lower block height
-        assert_eq!(finished_height, FinishedExExHeight::Height(42));
+    /// Polls the manager until the `ExEx` receives the next notification, e.g. one replayed from
+    /// the WAL in the background.
+    async fn next_notification<P: HeaderProvider + Unpin + 'static>(
+        mut exex_manager: Pin<&mut ExExManager<P>>,
+        notifications: &mut ExExNotifications<(), ()>,
+    ) -> eyre::Result<Option<ExExNotification>> {
+        poll_fn(|cx| {
+            if let Poll::Ready(result) = exex_manager.as_mut().poll(cx) {
+                return Poll::Ready(result.map(|()| None))
+            }
+            notifications.poll_next_unpin(cx).map(Ok)
+        })
+        .await
+    }
 
-        // // The lower block height should be retained
-        // let updated_exex_handle = &pinned_manager.exex_handles[0];
-        // assert_eq!(updated_exex_handle.finished_height, Some(42));
+    fn committed_notification(number: u64) -> ExExNotification {
+        let mut block = SealedBlockWithSenders::default();
+        block.block.header.set_hash(B256::with_last_byte(number as u8));
+        block.block.header.set_block_number(number);
+
+        ExExNotification::ChainCommitted {
+            new: Arc::new(Chain::new(vec![block], Default::default(), Default::default())),
+        }
     }
 
     #[tokio::test]
-    async fn test_exex_manager_capacity() {
+    async fn test_overflow_policy_block() -> eyre::Result<()> {
         let temp_dir = tempfile::tempdir().unwrap();
         let wal = Wal::new(temp_dir.path()).unwrap();
 
-        let provider_factory = create_test_provider_factory();
-
-        let (exex_handle_1, _, _) =
-            ExExHandle::new("test_exex_1".to_string(), Head::default(), (), (), wal.handle());
+        let (exex_handle, _, mut notifications) =
+            ExExHandle::new("test_exex".to_string(), Head::default(), (), (), wal.handle());
+        let exex_handle = exex_handle.with_max_buffered_notifications(2, ExExOverflowPolicy::Block);
 
-        // Create an ExExManager with a small max capacity
-        let max_capacity = 2;
-        let mut exex_manager = ExExManager::new(
-            provider_factory,
-            vec![exex_handle_1],
-            max_capacity,
-            Wal::new(temp_dir.path()).unwrap(),
+        let mut exex_manager = std::pin::pin!(ExExManager::new(
+            create_test_provider_factory(),
+            vec![exex_handle],
+            10,
+            wal,
             empty_finalized_header_stream(),
-        );
+        ));
+        let handle = exex_manager.handle();
 
         let mut cx = Context::from_waker(futures::task::noop_waker_ref());
 
-        // Setup a notification
-        let notification = ExExNotification::ChainCommitted {
-            new: Arc::new(Chain::new(
-                vec![Default::default()],
-                Default::default(),
-                Default::default(),
-            )),
-        };
+        for number in 0..4 {
+            handle.send(committed_notification(number))?;
+        }
+        assert!(exex_manager.as_mut().poll(&mut cx)?.is_pending());
 
-        // Send notifications to go over the max capacity
-        exex_manager.handle.exex_tx.send(notification.clone()).unwrap();
-        exex_manager.handle.exex_tx.send(notification.clone()).unwrap();
-        exex_manager.handle.exex_tx.send(notification).unwrap();
+        // Only two notifications were accepted into the buffer, and the first one was delivered
+        assert_eq!(exex_manager.next_id, 2);
+        assert!(handle.has_capacity());
 
-        // Pin the ExExManager to call the poll method
-        let mut pinned_manager = std::pin::pin!(exex_manager);
+        assert!(exex_manager.as_mut().poll(&mut cx)?.is_pending());
 
-        // Before polling, the next notification ID should be 0 and the buffer should be empty
-        assert_eq!(pinned_manager.next_id, 0);
-        assert_eq!(pinned_manager.buffer.len(), 0);
+        // One more notification was accepted, but the ExEx hasn't consumed the first one, so the
+        // manager applies backpressure until the ExEx catches up
+        assert_eq!(exex_manager.next_id, 3);
+        assert!(!handle.has_capacity());
 
-        let _ = pinned_manager.as_mut().poll(&mut cx);
+        assert_eq!(
+            notifications.next().poll_unpin(&mut cx),
+            Poll::Ready(Some(committed_notification(0)))
+        );
+        assert!(exex_manager.as_mut().poll(&mut cx)?.is_pending());
 
-        // After polling, the next notification ID and buffer size should be updated
-        assert_eq!(pinned_manager.next_id, 2);
-        assert_eq!(pinned_manager.buffer.len(), 2);
+        // The ExEx consumed one notification, so the manager has capacity again
+        assert_eq!(exex_manager.next_id, 3);
+        assert!(handle.has_capacity());
+
+        Ok(())
     }
 
     #[tokio::test]
-    async fn exex_handle_new() {
+    async fn test_overflow_policy_drop_oldest() -> eyre::Result<()> {
         let temp_dir = tempfile::tempdir().unwrap();
         let wal = Wal::new(temp_dir.path()).unwrap();
 
-        let (mut exex_handle, _, mut notifications) =
+        let (exex_handle, _, mut notifications) =
             ExExHandle::new("test_exex".to_string(), Head::default(), (), (), wal.handle());
+        let exex_handle =
+            exex_handle.with_max_buffered_notifications(2, ExExOverflowPolicy::DropOldest);
 
-        // Check initial state
-        assert_eq!(exex_handle.id, "test_exex");
-        assert_eq!(exex_handle.next_notification_id, 0);
-
-        // Setup two blocks for the chain commit notification
-        let mut block1 = SealedBlockWithSenders::default();
-        block1.block.header.set_hash(B256::new([0x01; 32]));
-        block1.block.header.set_block_number(10);
-
-        let mut block2 = SealedBlockWithSenders::default();
-        block2.block.header.set_hash(B256::new([0x02; 32]));
-        block2.block.header.set_block_number(11);
-
-        // Setup a notification
-        let notification = ExExNotification::ChainCommitted {
-            new: Arc::new(Chain::new(
-                vec![block1.clone(), block2.clone()],
-                Default::default(),
-                Default::default(),
-            )),
-        };
+        let mut exex_manager = std::pin::pin!(ExExManager::new(
+            create_test_provider_factory(),
+            vec![exex_handle],
+            10,
+            wal,
+            empty_finalized_header_stream(),
+        ));
 
         let mut cx = Context::from_waker(futures::task::noop_waker_ref());
 
-        // Send a notification and ensure it's received correctly
-        match exex_handle.send(&mut cx, &(22, notification.clone())) {
-            Poll::Ready(Ok(())) => {
-                let received_notification = notifications.next().await.unwrap();
-                assert_eq!(received_notification, notification);
-            }
-            Poll::Pending => panic!("Notification send is pending"),
-            Poll::Ready(Err(e)) => panic!("Failed to send notification: {:?}", e),
+        for number in 0..4 {
+            exex_manager.handle().send(committed_notification(number))?;
         }
+        assert!(exex_manager.as_mut().poll(&mut cx)?.is_pending());
 
-        // Ensure the notification ID was incremented
-        assert_eq!(exex_handle.next_notification_id, 23);
+        // The two oldest notifications were dropped, and the buffer was pruned up to the
+        // delivered one
+        assert_eq!(exex_manager.min_id, 3);
+        assert_eq!(
+            notifications.next().poll_unpin(&mut cx),
+            Poll::Ready(Some(committed_notification(2)))
+        );
+        assert!(exex_manager.as_mut().poll(&mut cx)?.is_pending());
+        assert_eq!(
+            notifications.next().poll_unpin(&mut cx),
+            Poll::Ready(Some(committed_notification(3)))
+        );
+
+        Ok(())
     }
 
     #[tokio::test]
-    async fn test_notification_if_finished_height_gt_chain_tip() {
+    async fn test_overflow_policy_error() -> eyre::Result<()> {
         let temp_dir = tempfile::tempdir().unwrap();
         let wal = Wal::new(temp_dir.path()).unwrap();
 
-        let (mut exex_handle, _, mut notifications) =
-            ExExHandle::new("test_exex".to_string(), Head::default(), (), (), wal.handle());
-
-        // Set finished_height to a value higher than the block tip
-        exex_handle.finished_height = Some(BlockNumHash::new(15, B256::random()));
-
-        let mut block1 = SealedBlockWithSenders::default();
-        block1.block.header.set_hash(B256::new([0x01; 32]));
-        block1.block.header.set_block_number(10);
+        let (exex_handle_1, _, mut notifications_1) =
+            ExExHandle::new("test_exex_1".to_string(), Head::default(), (), (), wal.handle());
+        let exex_handle_1 =
+            exex_handle_1.with_max_buffered_notifications(2, ExExOverflowPolicy::Error);
+        let stop_signal_1 = exex_handle_1.stop_signal();
+        let (exex_handle_2, _, mut notifications_2) =
+            ExExHandle::new("test_exex_2".to_string(), Head::default(), (), (), wal.handle());
 
-        let notification = ExExNotification::ChainCommitted {
-            new: Arc::new(Chain::new(vec![block1.clone()], Default::default(), Default::default())),
-        };
+        let mut exex_manager = std::pin::pin!(ExExManager::new(
+            create_test_provider_factory(),
+            vec![exex_handle_1, exex_handle_2],
+            10,
+            wal,
+            empty_finalized_header_stream(),
+        ));
 
         let mut cx = Context::from_waker(futures::task::noop_waker_ref());
 
-        // Send the notification
-        match exex_handle.send(&mut cx, &(22, notification)) {
-            Poll::Ready(Ok(())) => {
-                poll_fn(|cx| {
-                    // The notification should be skipped, so nothing should be sent.
-                    // Check that the receiver channel is indeed empty
-                    assert_eq!(
-                        notifications.poll_next_unpin(cx),
-                        Poll::Pending,
-                        "Receiver channel should be empty"
-                    );
-                    Poll::Ready(())
-                })
-                .await;
-            }
-            Poll::Pending | Poll::Ready(Err(_)) => {
-                panic!("Notification should not be pending or fail");
-            }
+        for number in 0..3 {
+            exex_manager.handle().send(committed_notification(number))?;
         }
+        assert!(exex_manager.as_mut().poll(&mut cx)?.is_pending());
 
-        // Ensure the notification ID was still incremented
-        assert_eq!(exex_handle.next_notification_id, 23);
+        // The first ExEx was terminated, so it's signaled to stop and its notifications stream ends
+        assert_eq!(exex_manager.exex_handles.len(), 1);
+        assert!(stop_signal_1.is_cancelled());
+        assert_eq!(notifications_1.next().poll_unpin(&mut cx), Poll::Ready(None));
+        assert_eq!(
+            *exex_manager.handle().statuses().borrow(),
+            vec![
+                ExExStatus {
+                    id: "test_exex_2".to_string(),
+                    finished_height: None,
+                    state: ExExState::Running
+                },
+                ExExStatus {
+                    id: "test_exex_1".to_string(),
+                    finished_height: None,
+                    state: ExExState::Terminated
+                },
+            ]
+        );
+
+        // The second ExEx still receives notifications
+        assert_eq!(
+            notifications_2.next().poll_unpin(&mut cx),
+            Poll::Ready(Some(committed_notification(0)))
+        );
+
+        Ok(())
     }
 
     #[tokio::test]
-    async fn test_sends_chain_reorged_notification() {
+    async fn test_rate_limit() -> eyre::Result<()> {
         let temp_dir = tempfile::tempdir().unwrap();
         let wal = Wal::new(temp_dir.path()).unwrap();
 
-        let (mut exex_handle, _, mut notifications) =
+        let (exex_handle, _, mut notifications) =
             ExExHandle::new("test_exex".to_string(), Head::default(), (), (), wal.handle());
+        let exex_handle = exex_handle.with_rate_limit(1, Duration::from_millis(50));
 
-        let notification = ExExNotification::ChainReorged {
-            old: Arc::new(Chain::default()),
-            new: Arc::new(Chain::default()),
-        };
-
-        // Even if the finished height is higher than the tip of the new chain, the reorg
-        // notification should be received
-        exex_handle.finished_height = Some(BlockNumHash::new(u64::MAX, B256::random()));
+        let mut exex_manager = std::pin::pin!(ExExManager::new(
+            create_test_provider_factory(),
+            vec![exex_handle],
+            10,
+            wal,
+            empty_finalized_header_stream(),
+        ));
 
         let mut cx = Context::from_waker(futures::task::noop_waker_ref());
 
-        // Send the notification
-        match exex_handle.send(&mut cx, &(22, notification.clone())) {
-            Poll::Ready(Ok(())) => {
-                let received_notification = notifications.next().await.unwrap();
-                assert_eq!(received_notification, notification);
-            }
-            Poll::Pending | Poll::Ready(Err(_)) => {
-                panic!("Notification should not be pending or fail")
-            }
+        for number in 0..2 {
+            exex_manager.handle().send(committed_notification(number))?;
         }
 
-        // Ensure the notification ID was incremented
-        assert_eq!(exex_handle.next_notification_id, 23);
+        // The first notification is delivered right away
+        assert!(exex_manager.as_mut().poll(&mut cx)?.is_pending());
+        assert_eq!(
+            notifications.next().poll_unpin(&mut cx),
+            Poll::Ready(Some(committed_notification(0)))
+        );
+
+        // The second notification stays buffered until the next period
+        assert!(exex_manager.as_mut().poll(&mut cx)?.is_pending());
+        assert_eq!(notifications.next().poll_unpin(&mut cx), Poll::Pending);
+        assert_eq!(exex_manager.buffer.len(), 1);
+
+        tokio::time::sleep(Duration::from_millis(60)).await;
+        assert!(exex_manager.as_mut().poll(&mut cx)?.is_pending());
+        assert_eq!(
+            notifications.next().poll_unpin(&mut cx),
+            Poll::Ready(Some(committed_notification(1)))
+        );
+
+        Ok(())
     }
 
-    #[tokio::test]
-    async fn test_sends_chain_reverted_notification() {
+    #[test]
+    #[should_panic(expected = "ExEx rate limit max blocks must be non-zero")]
+    fn test_rate_limit_zero_max_blocks() {
         let temp_dir = tempfile::tempdir().unwrap();
         let wal = Wal::new(temp_dir.path()).unwrap();
 
-        let (mut exex_handle, _, mut notifications) =
+        let (exex_handle, _, _) =
             ExExHandle::new("test_exex".to_string(), Head::default(), (), (), wal.handle());
+        let _ = exex_handle.with_rate_limit(0, Duration::from_secs(1));
+    }
 
-        let notification = ExExNotification::ChainReverted { old: Arc::new(Chain::default()) };
+    #[test]
+    #[should_panic(expected = "ExEx rate limit period must be non-zero")]
+    fn test_rate_limit_zero_period() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        let wal = Wal::new(temp_dir.path()).unwrap();
 
-        // Even if the finished height is higher than the tip of the new chain, the reorg
-        // notification should be received
-        exex_handle.finished_height = Some(BlockNumHash::new(u64::MAX, B256::random()));
+        let (exex_handle, _, _) =
+            ExExHandle::new("test_exex".to_string(), Head::default(), (), (), wal.handle());
+        let _ = exex_handle.with_rate_limit(1, Duration::ZERO);
+    }
+
+    #[tokio::test]
+    async fn test_watchdog_terminate() -> eyre::Result<()> {
+        let temp_dir = tempfile::tempdir().unwrap();
+        let wal = Wal::new(temp_dir.path()).unwrap();
+
+        let (exex_handle_1, _, mut notifications_1) =
+            ExExHandle::new("test_exex_1".to_string(), Head::default(), (), (), wal.handle());
+        let exex_handle_1 =
+            exex_handle_1.with_watchdog(Duration::from_millis(10), ExExWatchdogAction::Terminate);
+        let stop_signal_1 = exex_handle_1.stop_signal();
+        let (exex_handle_2, _, mut notifications_2) =
+            ExExHandle::new("test_exex_2".to_string(), Head::default(), (), (), wal.handle());
+
+        let mut exex_manager = std::pin::pin!(ExExManager::new(
+            create_test_provider_factory(),
+            vec![exex_handle_1, exex_handle_2],
+            10,
+            wal,
+            empty_finalized_header_stream(),
+        ));
 
         let mut cx = Context::from_waker(futures::task::noop_waker_ref());
 
-        // Send the notification
-        match exex_handle.send(&mut cx, &(22, notification.clone())) {
-            Poll::Ready(Ok(())) => {
-                let received_notification = notifications.next().await.unwrap();
-                assert_eq!(received_notification, notification);
-            }
-            Poll::Pending | Poll::Ready(Err(_)) => {
-                panic!("Notification should not be pending or fail")
-            }
-        }
+        exex_manager.handle().send(committed_notification(0))?;
+        assert!(exex_manager.as_mut().poll(&mut cx)?.is_pending());
 
-        // Ensure the notification ID was incremented
-        assert_eq!(exex_handle.next_notification_id, 23);
+        // Both ExExes receive the notification, but none of them emits a finished height
+        assert_eq!(
+            notifications_1.next().poll_unpin(&mut cx),
+            Poll::Ready(Some(committed_notification(0)))
+        );
+        assert_eq!(
+            notifications_2.next().poll_unpin(&mut cx),
+            Poll::Ready(Some(committed_notification(0)))
+        );
+        assert_eq!(exex_manager.exex_handles.len(), 2);
+
+        tokio::time::sleep(Duration::from_millis(20)).await;
+        assert!(exex_manager.as_mut().poll(&mut cx)?.is_pending());
+
+        // The first ExEx made no progress for the watchdog timeout, so it was terminated and
+        // signaled to stop, while the second ExEx without a watchdog is still running
+        assert_eq!(exex_manager.exex_handles.len(), 1);
+        assert!(stop_signal_1.is_cancelled());
+        assert_eq!(notifications_1.next().poll_unpin(&mut cx), Poll::Ready(None));
+        assert_eq!(
+            *exex_manager.handle().statuses().borrow(),
+            vec![
+                ExExStatus {
+                    id: "test_exex_2".to_string(),
+                    finished_height: None,
+                    state: ExExState::Running
+                },
+                ExExStatus {
+                    id: "test_exex_1".to_string(),
+                    finished_height: None,
+                    state: ExExState::Terminated
+                },
+            ]
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    #[should_panic(expected = "ExEx watchdog timeout must be non-zero")]
+    fn test_watchdog_zero_timeout() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        let wal = Wal::new(temp_dir.path()).unwrap();
+
+        let (exex_handle, _, _) =
+            ExExHandle::new("test_exex".to_string(), Head::default(), (), (), wal.handle());
+        let _ = exex_handle.with_watchdog(Duration::ZERO, ExExWatchdogAction::Warn);
     }
 
     #[tokio::test]
@@ -1211,4 +3452,199 @@ mod tests {
 
         Ok(())
     }
+
+    #[tokio::test]
+    async fn test_exex_finished_heights_persisted_on_change() -> eyre::Result<()> {
+        let temp_dir = tempfile::tempdir().unwrap();
+        let wal = Wal::new(temp_dir.path().join("wal"))?;
+
+        let (exex_handle, events_tx, _notifications) =
+            ExExHandle::new("test_exex".to_string(), Head::default(), (), (), wal.handle());
+        let mut exex_manager = std::pin::pin!(ExExManager::new(
+            create_test_provider_factory(),
+            vec![exex_handle],
+            10,
+            wal,
+            empty_finalized_header_stream(),
+        )
+        .with_finished_heights_storage(FinishedHeightsStorage::new(
+            temp_dir.path().join("finished_heights")
+        )?)?);
+
+        let mut cx = Context::from_waker(futures::task::noop_waker_ref());
+
+        // A new finished height is written to the storage
+        let height = BlockNumHash::new(1, B256::with_last_byte(1));
+        events_tx.send(ExExEvent::FinishedHeight(height))?;
+        assert!(exex_manager.as_mut().poll(&mut cx)?.is_pending());
+        assert!(exex_manager.finished_heights_write.is_some());
+        poll_fn(|cx| exex_manager.poll_persist_finished_heights(cx)).await;
+
+        // The same finished height is not written again
+        events_tx.send(ExExEvent::FinishedHeight(height))?;
+        assert!(exex_manager.as_mut().poll(&mut cx)?.is_pending());
+        assert!(!exex_manager.stored_finished_heights_changed);
+        assert!(exex_manager.finished_heights_write.is_none());
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_exex_finished_heights_persisted_across_restarts() -> eyre::Result<()> {
+        let mut rng = generators::rng();
+
+        let temp_dir = tempfile::tempdir().unwrap();
+        let wal_path = temp_dir.path().join("wal");
+        let finished_heights_path = temp_dir.path().join("finished_heights");
+
+        let provider_factory = create_test_provider_factory();
+
+        let block_1 = random_block(&mut rng, 1, Default::default())
+            .seal_with_senders()
+            .ok_or_eyre("failed to recover senders")?;
+        let block_2 = random_block(&mut rng, 2, Default::default())
+            .seal_with_senders()
+            .ok_or_eyre("failed to recover senders")?;
+        let notification_1 = ExExNotification::ChainCommitted {
+            new: Arc::new(Chain::new(vec![block_1.clone()], Default::default(), None)),
+        };
+        let notification_2 = ExExNotification::ChainCommitted {
+            new: Arc::new(Chain::new(vec![block_2], Default::default(), None)),
+        };
+
+        let mut cx = Context::from_waker(futures::task::noop_waker_ref());
+
+        {
+            let wal = Wal::new(&wal_path)?;
+            let (exex_handle, events_tx, mut notifications) =
+                ExExHandle::new("test_exex".to_string(), Head::default(), (), (), wal.handle());
+            let mut exex_manager = std::pin::pin!(ExExManager::new(
+                provider_factory.clone(),
+                vec![exex_handle],
+                10,
+                wal,
+                empty_finalized_header_stream(),
+            )
+            .with_finished_heights_storage(FinishedHeightsStorage::new(&finished_heights_path)?)?);
+
+            exex_manager.handle().send(notification_1.clone())?;
+            assert!(exex_manager.as_mut().poll(&mut cx)?.is_pending());
+            assert_eq!(
+                notifications.next().poll_unpin(&mut cx),
+                Poll::Ready(Some(notification_1.clone()))
+            );
+
+            events_tx.send(ExExEvent::FinishedHeight(block_1.num_hash()))?;
+            assert!(exex_manager.as_mut().poll(&mut cx)?.is_pending());
+
+            // Wait for the finished heights to be persisted in the background
+            poll_fn(|cx| exex_manager.poll_persist_finished_heights(cx)).await;
+
+            // The manager is dropped here, simulating a node shutdown
+        }
+
+        let wal = Wal::new(&wal_path)?;
+        let (exex_handle, _, mut notifications) =
+            ExExHandle::new("test_exex".to_string(), Head::default(), (), (), wal.handle());
+        let mut exex_manager = std::pin::pin!(ExExManager::new(
+            provider_factory,
+            vec![exex_handle],
+            10,
+            wal,
+            empty_finalized_header_stream(),
+        )
+        .with_finished_heights_storage(FinishedHeightsStorage::new(&finished_heights_path)?)?);
+
+        // The finished height is restored after the restart
+        assert_eq!(exex_manager.exex_handles[0].finished_height, Some(block_1.num_hash()));
+
+        exex_manager.handle().send(notification_1)?;
+        exex_manager.handle().send(notification_2.clone())?;
+        assert!(exex_manager.as_mut().poll(&mut cx)?.is_pending());
+        assert!(exex_manager.as_mut().poll(&mut cx)?.is_pending());
+
+        // The already processed notification is not delivered again, and the new one is delivered
+        assert_eq!(notifications.next().poll_unpin(&mut cx), Poll::Ready(Some(notification_2)));
+        assert!(notifications.next().poll_unpin(&mut cx).is_pending());
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_register_exex_resumes_from_persisted_height() -> eyre::Result<()> {
+        let temp_dir = tempfile::tempdir().unwrap();
+        let wal_path = temp_dir.path().join("wal");
+        let finished_heights_path = temp_dir.path().join("finished_heights");
+        let height_1 = BlockNumHash::new(1, B256::with_last_byte(1));
+        let height_2 = BlockNumHash::new(2, B256::with_last_byte(2));
+
+        let mut cx = Context::from_waker(futures::task::noop_waker_ref());
+
+        {
+            let wal = Wal::new(&wal_path)?;
+            let (exex_handle_1, events_tx_1, _notifications_1) =
+                ExExHandle::new("test_exex_1".to_string(), Head::default(), (), (), wal.handle());
+            let (exex_handle_2, events_tx_2, _notifications_2) =
+                ExExHandle::new("test_exex_2".to_string(), Head::default(), (), (), wal.handle());
+            let mut exex_manager = std::pin::pin!(ExExManager::new(
+                create_test_provider_factory(),
+                vec![exex_handle_1, exex_handle_2],
+                10,
+                wal,
+                empty_finalized_header_stream(),
+            )
+            .with_finished_heights_storage(FinishedHeightsStorage::new(&finished_heights_path)?)?);
+
+            events_tx_1.send(ExExEvent::FinishedHeight(height_1))?;
+            events_tx_2.send(ExExEvent::FinishedHeight(height_1))?;
+            assert!(exex_manager.as_mut().poll(&mut cx)?.is_pending());
+            poll_fn(|cx| exex_manager.poll_persist_finished_heights(cx)).await;
+        }
+
+        // The node restarts without the second ExEx, and the first ExEx makes progress
+        let wal = Wal::new(&wal_path)?;
+        let (exex_handle_1, events_tx_1, _notifications_1) =
+            ExExHandle::new("test_exex_1".to_string(), Head::default(), (), (), wal.handle());
+        let mut exex_manager = std::pin::pin!(ExExManager::new(
+            create_test_provider_factory(),
+            vec![exex_handle_1],
+            10,
+            wal.clone(),
+            empty_finalized_header_stream(),
+        )
+        .with_finished_heights_storage(FinishedHeightsStorage::new(&finished_heights_path)?)?);
+
+        events_tx_1.send(ExExEvent::FinishedHeight(height_2))?;
+        assert!(exex_manager.as_mut().poll(&mut cx)?.is_pending());
+        poll_fn(|cx| exex_manager.poll_persist_finished_heights(cx)).await;
+
+        // The finished height of the second ExEx is kept in the storage
+        assert_eq!(
+            FinishedHeightsStorage::new(&finished_heights_path)?.read()?,
+            BTreeMap::from([
+                ("test_exex_1".to_string(), height_2),
+                ("test_exex_2".to_string(), height_1),
+            ])
+        );
+
+        // The second ExEx resumes from its persisted finished height when registered again
+        let (exex_handle_2, _, _notifications_2) =
+            ExExHandle::new("test_exex_2".to_string(), Head::default(), (), (), wal.handle());
+        exex_manager.register_exex(exex_handle_2)?;
+        assert_eq!(
+            exex_manager
+                .exex_handles
+                .iter()
+                .find(|exex| exex.id == "test_exex_2")
+                .and_then(|exex| exex.finished_height),
+            Some(height_1)
+        );
+        assert!(exex_manager.as_mut().poll(&mut cx)?.is_pending());
+        assert_eq!(
+            *exex_manager.handle().finished_height().borrow(),
+            FinishedExExHeight::Height(1)
+        );
+
+        Ok(())
+    }
 }
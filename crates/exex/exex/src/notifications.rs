@@ -1,4 +1,4 @@
-use crate::{BackfillJobFactory, ExExNotification, StreamBackfillJob, WalHandle};
+use crate::{BackfillJobFactory, ExExNotification, RecentBlocks, StreamBackfillJob, WalHandle};
 use futures::{Stream, StreamExt};
 use reth_chainspec::Head;
 use reth_evm::execute::BlockExecutorProvider;
@@ -20,6 +20,7 @@ pub struct ExExNotifications<P, E> {
     executor: E,
     notifications: Receiver<ExExNotification>,
     wal_handle: WalHandle,
+    recent_blocks: RecentBlocks,
 }
 
 impl<P: Debug, E: Debug> Debug for ExExNotifications<P, E> {
@@ -28,6 +29,7 @@ impl<P: Debug, E: Debug> Debug for ExExNotifications<P, E> {
             .field("provider", &self.provider)
             .field("executor", &self.executor)
             .field("notifications", &self.notifications)
+            .field("recent_blocks", &self.recent_blocks)
             .finish()
     }
 }
@@ -41,7 +43,30 @@ impl<P, E> ExExNotifications<P, E> {
         notifications: Receiver<ExExNotification>,
         wal_handle: WalHandle,
     ) -> Self {
-        Self { node_head, provider, executor, notifications, wal_handle }
+        Self {
+            node_head,
+            provider,
+            executor,
+            notifications,
+            wal_handle,
+            recent_blocks: RecentBlocks::disabled(),
+        }
+    }
+
+    /// Retains up to `capacity` most recent blocks delivered by the stream, accessible via
+    /// [`Self::recent_blocks`].
+    ///
+    /// The blocks retained so far are discarded.
+    pub fn set_recent_blocks_capacity(&mut self, capacity: usize) {
+        self.recent_blocks = RecentBlocks::new(capacity);
+    }
+
+    /// Returns the buffer of the most recent blocks delivered by the stream.
+    ///
+    /// The buffer doesn't retain any blocks, unless configured with
+    /// [`Self::set_recent_blocks_capacity`].
+    pub const fn recent_blocks(&self) -> &RecentBlocks {
+        &self.recent_blocks
     }
 
     /// Receives the next value for this receiver.
@@ -108,14 +133,16 @@ where
     /// with `block.number == 11`. A `head.number` of 10 indicates that the ExEx
     /// has processed up to block 10, and is ready to process block 11.
     pub fn with_head(self, head: ExExHead) -> ExExNotificationsWithHead<P, E> {
-        ExExNotificationsWithHead::new(
+        let mut notifications = ExExNotificationsWithHead::new(
             self.node_head,
             self.provider,
             self.executor,
             self.notifications,
             self.wal_handle,
             head,
-        )
+        );
+        notifications.recent_blocks = self.recent_blocks;
+        notifications
     }
 }
 
@@ -123,7 +150,14 @@ impl<P: Unpin, E: Unpin> Stream for ExExNotifications<P, E> {
     type Item = ExExNotification;
 
     fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
-        self.get_mut().notifications.poll_recv(cx)
+        let this = self.get_mut();
+
+        let notification = ready!(this.notifications.poll_recv(cx));
+        if let Some(notification) = &notification {
+            this.recent_blocks.on_notification(notification);
+        }
+
+        Poll::Ready(notification)
     }
 }
 
@@ -145,6 +179,8 @@ pub struct ExExNotificationsWithHead<P, E> {
     pending_check_backfill: bool,
     /// The backfill job to run before consuming any notifications.
     backfill_job: Option<StreamBackfillJob<E, P, Chain>>,
+    /// The buffer of the most recent blocks delivered by the stream.
+    recent_blocks: RecentBlocks,
 }
 
 impl<P, E> ExExNotificationsWithHead<P, E>
@@ -171,9 +207,29 @@ where
             pending_check_canonical: true,
             pending_check_backfill: true,
             backfill_job: None,
+            recent_blocks: RecentBlocks::disabled(),
         }
     }
 
+    /// Returns the buffer of the most recent blocks delivered by the stream.
+    ///
+    /// The buffer doesn't retain any blocks, unless configured with
+    /// [`ExExNotifications::set_recent_blocks_capacity`].
+    pub const fn recent_blocks(&self) -> &RecentBlocks {
+        &self.recent_blocks
+    }
+
+    /// Returns `true` if the stream has caught up with the node head, and only yields the live
+    /// notifications from the manager.
+    ///
+    /// Until then, the stream yields the notifications reverting a non-canonical ExEx head and the
+    /// notifications with the blocks backfilled from the ExEx head to the node head. It stays
+    /// `false` after the last backfilled notification was yielded, until the next notification is
+    /// polled.
+    pub const fn is_synced(&self) -> bool {
+        !self.pending_check_canonical && !self.pending_check_backfill && self.backfill_job.is_none()
+    }
+
     /// Checks if the ExEx head is on the canonical chain.
     ///
     /// If the head block is not found in the database or it's ahead of the node head, it means
@@ -244,33 +300,28 @@ where
 
         Ok(())
     }
-}
 
-impl<P, E> Stream for ExExNotificationsWithHead<P, E>
-where
-    P: BlockReader + HeaderProvider + StateProviderFactory + Clone + Unpin + 'static,
-    E: BlockExecutorProvider + Clone + Unpin + 'static,
-{
-    type Item = eyre::Result<ExExNotification>;
-
-    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
-        let this = self.get_mut();
-
-        if this.pending_check_canonical {
-            if let Some(canonical_notification) = this.check_canonical()? {
+    /// Polls the next notification, checking the ExEx head and running the backfill first, if
+    /// needed.
+    fn poll_next_notification(
+        &mut self,
+        cx: &mut Context<'_>,
+    ) -> Poll<Option<eyre::Result<ExExNotification>>> {
+        if self.pending_check_canonical {
+            if let Some(canonical_notification) = self.check_canonical()? {
                 return Poll::Ready(Some(Ok(canonical_notification)))
             }
 
             // ExEx head is on the canonical chain, we no longer need to check it
-            this.pending_check_canonical = false;
+            self.pending_check_canonical = false;
         }
 
-        if this.pending_check_backfill {
-            this.check_backfill()?;
-            this.pending_check_backfill = false;
+        if self.pending_check_backfill {
+            self.check_backfill()?;
+            self.pending_check_backfill = false;
         }
 
-        if let Some(backfill_job) = &mut this.backfill_job {
+        if let Some(backfill_job) = &mut self.backfill_job {
             if let Some(chain) = ready!(backfill_job.poll_next_unpin(cx)) {
                 return Poll::Ready(Some(Ok(ExExNotification::ChainCommitted {
                     new: Arc::new(chain?),
@@ -278,24 +329,43 @@ where
             }
 
             // Backfill job is done, remove it
-            this.backfill_job = None;
+            self.backfill_job = None;
         }
 
-        let Some(notification) = ready!(this.notifications.poll_recv(cx)) else {
+        let Some(notification) = ready!(self.notifications.poll_recv(cx)) else {
             return Poll::Ready(None)
         };
 
         if let Some(committed_chain) = notification.committed_chain() {
-            this.exex_head.block = committed_chain.tip().num_hash();
+            self.exex_head.block = committed_chain.tip().num_hash();
         } else if let Some(reverted_chain) = notification.reverted_chain() {
             let first_block = reverted_chain.first();
-            this.exex_head.block = (first_block.parent_hash, first_block.number - 1).into();
+            self.exex_head.block = (first_block.parent_hash, first_block.number - 1).into();
         }
 
         Poll::Ready(Some(Ok(notification)))
     }
 }
 
+impl<P, E> Stream for ExExNotificationsWithHead<P, E>
+where
+    P: BlockReader + HeaderProvider + StateProviderFactory + Clone + Unpin + 'static,
+    E: BlockExecutorProvider + Clone + Unpin + 'static,
+{
+    type Item = eyre::Result<ExExNotification>;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let this = self.get_mut();
+
+        let notification = ready!(this.poll_next_notification(cx));
+        if let Some(Ok(notification)) = &notification {
+            this.recent_blocks.on_notification(notification);
+        }
+
+        Poll::Ready(notification)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use crate::Wal;
@@ -375,6 +445,7 @@ mod tests {
             wal.handle(),
         )
         .with_head(exex_head);
+        assert!(!notifications.is_synced());
 
         // First notification is the backfill of missing blocks from the canonical chain
         assert_eq!(
@@ -391,9 +462,11 @@ mod tests {
                 )
             })
         );
+        assert!(!notifications.is_synced());
 
         // Second notification is the actual notification that we sent before
         assert_eq!(notifications.next().await.transpose()?, Some(notification));
+        assert!(notifications.is_synced());
 
         Ok(())
     }
@@ -0,0 +1,132 @@
+use std::{collections::VecDeque, sync::Arc};
+
+use parking_lot::RwLock;
+use reth_primitives::SealedBlock;
+
+use crate::ExExNotification;
+
+/// A bounded buffer of the most recent blocks delivered to an `ExEx`.
+///
+/// The buffer follows the notifications as they're consumed from the stream, so that the blocks
+/// reverted by a reorg or a revert are removed from the buffer and blocks from an abandoned fork
+/// are never returned.
+///
+/// The buffer is shared between its clones.
+#[derive(Debug, Clone, Default)]
+pub struct RecentBlocks {
+    /// The buffer, or `None` if no blocks are retained.
+    inner: Option<Arc<RwLock<RecentBlocksInner>>>,
+}
+
+#[derive(Debug)]
+struct RecentBlocksInner {
+    /// The maximum number of blocks to retain.
+    capacity: usize,
+    /// The blocks, ordered by block number in ascending order.
+    blocks: VecDeque<SealedBlock>,
+}
+
+impl RecentBlocks {
+    /// Creates a new [`RecentBlocks`] buffer retaining up to `capacity` blocks.
+    pub fn new(capacity: usize) -> Self {
+        if capacity == 0 {
+            return Self::disabled()
+        }
+
+        Self {
+            inner: Some(Arc::new(RwLock::new(RecentBlocksInner {
+                capacity,
+                blocks: VecDeque::with_capacity(capacity),
+            }))),
+        }
+    }
+
+    /// Creates a new [`RecentBlocks`] buffer that doesn't retain any blocks.
+    pub const fn disabled() -> Self {
+        Self { inner: None }
+    }
+
+    /// Returns the maximum number of blocks retained by the buffer.
+    pub fn capacity(&self) -> usize {
+        self.inner.as_ref().map_or(0, |inner| inner.read().capacity)
+    }
+
+    /// Returns up to `n` most recent blocks, ordered by block number in ascending order.
+    pub fn last(&self, n: usize) -> Vec<SealedBlock> {
+        let Some(inner) = &self.inner else { return Vec::new() };
+        let inner = inner.read();
+        inner.blocks.iter().skip(inner.blocks.len().saturating_sub(n)).cloned().collect()
+    }
+
+    /// Updates the buffer with the notification delivered to the `ExEx`.
+    pub(crate) fn on_notification(&self, notification: &ExExNotification) {
+        let Some(inner) = &self.inner else { return };
+        let mut inner = inner.write();
+
+        if let Some(reverted_chain) = notification.reverted_chain() {
+            inner.truncate_from(reverted_chain.first().number);
+        }
+
+        if let Some(committed_chain) = notification.committed_chain() {
+            inner.truncate_from(committed_chain.first().number);
+            inner.blocks.extend(committed_chain.blocks_iter().map(|block| block.block.clone()));
+
+            let excess = inner.blocks.len().saturating_sub(inner.capacity);
+            inner.blocks.drain(..excess);
+        }
+    }
+}
+
+impl RecentBlocksInner {
+    /// Removes all blocks with the number higher than or equal to the given one.
+    fn truncate_from(&mut self, block_number: u64) {
+        while self.blocks.back().is_some_and(|block| block.number >= block_number) {
+            self.blocks.pop_back();
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use reth_provider::Chain;
+    use reth_testing_utils::generators::{self, random_block_range, BlockRangeParams};
+
+    use super::RecentBlocks;
+    use crate::ExExNotification;
+
+    #[test]
+    fn test_recent_blocks() {
+        let mut rng = generators::rng();
+
+        let blocks = random_block_range(&mut rng, 1..=4, BlockRangeParams::default());
+        let chain = |blocks: &[reth_primitives::SealedBlock]| {
+            Arc::new(Chain::new(
+                blocks.iter().map(|block| block.clone().seal_with_senders().unwrap()),
+                Default::default(),
+                None,
+            ))
+        };
+
+        let recent_blocks = RecentBlocks::new(3);
+        recent_blocks
+            .on_notification(&ExExNotification::ChainCommitted { new: chain(&blocks[..2]) });
+        recent_blocks
+            .on_notification(&ExExNotification::ChainCommitted { new: chain(&blocks[2..]) });
+
+        // Only the last 3 blocks are retained
+        assert_eq!(recent_blocks.last(10), blocks[1..]);
+        assert_eq!(recent_blocks.last(1), blocks[3..]);
+
+        // Reverted blocks are removed
+        recent_blocks
+            .on_notification(&ExExNotification::ChainReverted { old: chain(&blocks[2..]) });
+        assert_eq!(recent_blocks.last(10), blocks[1..2]);
+
+        // A disabled buffer doesn't retain any blocks
+        let recent_blocks = RecentBlocks::disabled();
+        recent_blocks.on_notification(&ExExNotification::ChainCommitted { new: chain(&blocks) });
+        assert!(recent_blocks.last(10).is_empty());
+    }
+}
@@ -0,0 +1,189 @@
+//! Publishing of `ExEx` statuses over a Unix domain socket.
+
+use std::{io, os::unix::fs::FileTypeExt, path::PathBuf};
+
+use alloy_primitives::BlockNumber;
+use reth_tracing::tracing::{debug, trace};
+use tokio::{
+    io::AsyncWriteExt,
+    net::{UnixListener, UnixStream},
+    sync::watch,
+};
+
+use crate::{ExExManagerHandle, ExExStatus};
+
+/// Publishes the progress of all `ExEx`'s over a Unix domain socket.
+///
+/// Every connection to the socket receives the current progress of each `ExEx` as newline-delimited
+/// JSON objects with the `id`, `finished_height` and `head` fields, after which the connection
+/// is closed. External monitoring can poll the socket without the `ExEx`'s having to implement any
+/// networking themselves.
+#[derive(Debug)]
+pub struct ExExStatusSocket {
+    /// The path of the Unix domain socket.
+    path: PathBuf,
+    /// The statuses of all `ExEx`'s.
+    statuses: watch::Receiver<Vec<ExExStatus>>,
+    /// The latest block number of the node.
+    head: watch::Receiver<Option<BlockNumber>>,
+}
+
+impl ExExStatusSocket {
+    /// Creates a new [`ExExStatusSocket`] publishing the progress of the `ExEx`'s managed by the
+    /// manager of the given handle.
+    pub fn new(path: impl Into<PathBuf>, handle: &ExExManagerHandle) -> Self {
+        Self { path: path.into(), statuses: handle.statuses(), head: handle.head() }
+    }
+
+    /// Binds the socket and serves the connections until an I/O error occurs.
+    ///
+    /// A stale socket left at the path by a previous run is removed before binding. Any other file
+    /// at the path is left untouched, and an error is returned instead. Each connection is served
+    /// by its own task, so that a slow client doesn't block the others.
+    pub async fn serve(self) -> io::Result<()> {
+        match std::fs::symlink_metadata(&self.path) {
+            Ok(metadata) if metadata.file_type().is_socket() => {
+                reth_fs_util::remove_file(&self.path).map_err(io::Error::other)?;
+            }
+            Ok(_) => {
+                return Err(io::Error::new(
+                    io::ErrorKind::AlreadyExists,
+                    format!("{} already exists and is not a socket", self.path.display()),
+                ))
+            }
+            Err(err) if err.kind() == io::ErrorKind::NotFound => {}
+            Err(err) => return Err(err),
+        }
+
+        let listener = UnixListener::bind(&self.path)?;
+        debug!(target: "exex::status_socket", path = %self.path.display(), "Serving ExEx statuses");
+
+        loop {
+            let (stream, _) = listener.accept().await?;
+            trace!(target: "exex::status_socket", "Accepted connection");
+
+            let payload = match self.payload() {
+                Ok(payload) => payload,
+                Err(err) => {
+                    debug!(target: "exex::status_socket", %err, "Failed to encode ExEx statuses");
+                    continue
+                }
+            };
+
+            tokio::spawn(async move {
+                if let Err(err) = write_payload(stream, &payload).await {
+                    debug!(target: "exex::status_socket", %err, "Failed to write ExEx statuses");
+                }
+            });
+        }
+    }
+
+    /// Returns the newline-delimited JSON payload with the current progress of each `ExEx`.
+    fn payload(&self) -> io::Result<Vec<u8>> {
+        let head = *self.head.borrow();
+
+        let mut payload = Vec::new();
+        for status in self.statuses.borrow().iter() {
+            let line = serde_json::json!({
+                "id": status.id,
+                "finished_height": status.finished_height,
+                "head": head,
+            });
+            serde_json::to_writer(&mut payload, &line)?;
+            payload.push(b'\n');
+        }
+
+        Ok(payload)
+    }
+}
+
+/// Writes the payload to the stream and closes it.
+async fn write_payload(mut stream: UnixStream, payload: &[u8]) -> io::Result<()> {
+    stream.write_all(payload).await?;
+    stream.shutdown().await
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use reth_chain_state::ForkChoiceStream;
+    use reth_chainspec::Head;
+    use tokio::{io::AsyncReadExt, net::UnixStream, sync::watch};
+
+    use crate::{wal::Wal, ExExHandle, ExExManager, ExExStatusSocket};
+
+    #[tokio::test]
+    async fn test_status_socket() -> eyre::Result<()> {
+        let temp_dir = tempfile::tempdir()?;
+        let wal = Wal::new(temp_dir.path().join("wal"))?;
+
+        let (exex_handle, _, _) =
+            ExExHandle::new("test_exex".to_string(), Head::default(), (), (), wal.handle());
+
+        let (_finalized_header_tx, finalized_header_rx) = watch::channel(None);
+        let exex_manager = ExExManager::new(
+            (),
+            vec![exex_handle],
+            10,
+            wal,
+            ForkChoiceStream::new(finalized_header_rx),
+        );
+
+        let path = temp_dir.path().join("exex.sock");
+        tokio::spawn(ExExStatusSocket::new(&path, &exex_manager.handle()).serve());
+
+        let mut stream = loop {
+            match UnixStream::connect(&path).await {
+                Ok(stream) => break stream,
+                Err(_) => tokio::time::sleep(Duration::from_millis(10)).await,
+            }
+        };
+        let mut payload = String::new();
+        stream.read_to_string(&mut payload).await?;
+
+        let lines = payload
+            .lines()
+            .map(serde_json::from_str)
+            .collect::<Result<Vec<serde_json::Value>, _>>()?;
+        assert_eq!(
+            lines,
+            vec![serde_json::json!({ "id": "test_exex", "finished_height": null, "head": null })]
+        );
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_status_socket_existing_file() -> eyre::Result<()> {
+        let temp_dir = tempfile::tempdir()?;
+        let wal = Wal::new(temp_dir.path().join("wal"))?;
+
+        let (_finalized_header_tx, finalized_header_rx) = watch::channel(None);
+        let exex_manager =
+            ExExManager::new((), vec![], 10, wal, ForkChoiceStream::new(finalized_header_rx));
+
+        // A file that is not a socket is not removed
+        let path = temp_dir.path().join("exex.sock");
+        std::fs::write(&path, "not a socket")?;
+        let err = ExExStatusSocket::new(&path, &exex_manager.handle()).serve().await.unwrap_err();
+        assert_eq!(err.kind(), std::io::ErrorKind::AlreadyExists);
+        assert_eq!(std::fs::read_to_string(&path)?, "not a socket");
+
+        // A stale socket is removed and bound again
+        std::fs::remove_file(&path)?;
+        drop(std::os::unix::net::UnixListener::bind(&path)?);
+        tokio::spawn(ExExStatusSocket::new(&path, &exex_manager.handle()).serve());
+        let mut stream = loop {
+            match UnixStream::connect(&path).await {
+                Ok(stream) => break stream,
+                Err(_) => tokio::time::sleep(Duration::from_millis(10)).await,
+            }
+        };
+        let mut payload = String::new();
+        stream.read_to_string(&mut payload).await?;
+        assert!(payload.is_empty());
+
+        Ok(())
+    }
+}
@@ -0,0 +1,126 @@
+//! Stream wrapper that filters notifications by the addresses they touch.
+
+use std::{
+    collections::HashSet,
+    pin::Pin,
+    task::{ready, Context, Poll},
+};
+
+use alloy_primitives::Address;
+use futures::{Stream, StreamExt};
+use reth_provider::Chain;
+use reth_tracing::tracing::trace;
+
+use crate::ExExNotification;
+
+/// [`ExExNotification`] stream wrapper that only yields committed chains touching any of the
+/// given addresses.
+///
+/// A chain touches an address if any of its transactions is sent by or to the address, or if any
+/// of its receipts contains a log emitted by the address.
+///
+/// [`ExExNotification::ChainReorged`] and [`ExExNotification::ChainReverted`] notifications are
+/// always yielded, regardless of the addresses they touch, so that the consumer can unwind its
+/// state for the reverted blocks.
+#[derive(Debug)]
+pub struct ExExAddressFilter<S> {
+    stream: S,
+    /// The addresses to filter the committed chains by.
+    addresses: HashSet<Address>,
+}
+
+impl<S> ExExAddressFilter<S> {
+    /// Creates new [`ExExAddressFilter`] stream wrapper.
+    pub const fn new(stream: S, addresses: HashSet<Address>) -> Self {
+        Self { stream, addresses }
+    }
+
+    /// Returns `true` if any of the blocks in the chain touches any of the addresses.
+    fn touches_addresses(&self, chain: &Chain) -> bool {
+        let touched_by_transactions = chain.blocks_iter().any(|block| {
+            block.transactions_with_sender().any(|(sender, transaction)| {
+                self.addresses.contains(sender) ||
+                    transaction.to().is_some_and(|to| self.addresses.contains(&to))
+            })
+        });
+
+        touched_by_transactions ||
+            chain.block_receipts_iter().flatten().flatten().any(|receipt| {
+                receipt.logs.iter().any(|log| self.addresses.contains(&log.address))
+            })
+    }
+}
+
+impl<S> Stream for ExExAddressFilter<S>
+where
+    S: Stream<Item = ExExNotification> + Unpin,
+{
+    type Item = ExExNotification;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let this = self.get_mut();
+
+        loop {
+            let Some(notification) = ready!(this.stream.poll_next_unpin(cx)) else {
+                return Poll::Ready(None)
+            };
+
+            if let ExExNotification::ChainCommitted { new } = &notification {
+                if !this.touches_addresses(new) {
+                    trace!(target: "exex::stream::address_filter", range = ?new.range(), "Skipping committed chain");
+                    continue
+                }
+            }
+
+            return Poll::Ready(Some(notification))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use eyre::OptionExt;
+    use futures::{stream, StreamExt};
+    use reth_provider::Chain;
+    use reth_testing_utils::generators::{self, random_block, BlockParams};
+
+    use crate::{ExExNotification, ExExNotificationStreamExt};
+
+    #[tokio::test]
+    async fn test_address_filter() -> eyre::Result<()> {
+        let mut rng = generators::rng();
+
+        let block_1 =
+            random_block(&mut rng, 1, BlockParams { tx_count: Some(1), ..Default::default() })
+                .seal_with_senders()
+                .ok_or_eyre("failed to recover senders")?;
+        let block_2 =
+            random_block(&mut rng, 2, BlockParams { tx_count: Some(1), ..Default::default() })
+                .seal_with_senders()
+                .ok_or_eyre("failed to recover senders")?;
+        let sender = block_1.senders[0];
+
+        let committed_1 = ExExNotification::ChainCommitted {
+            new: Arc::new(Chain::new(vec![block_1], Default::default(), None)),
+        };
+        let committed_2 = ExExNotification::ChainCommitted {
+            new: Arc::new(Chain::new(vec![block_2.clone()], Default::default(), None)),
+        };
+        let reverted_2 = ExExNotification::ChainReverted {
+            old: Arc::new(Chain::new(vec![block_2], Default::default(), None)),
+        };
+
+        let notifications = stream::iter([committed_1.clone(), committed_2, reverted_2.clone()])
+            .with_address_filter([sender].into())
+            .collect::<Vec<_>>()
+            .await;
+
+        // The second committed chain doesn't touch the sender of the first block, but the revert
+        // is still delivered
+        assert_eq!(notifications, vec![committed_1, reverted_2]);
+
+        Ok(())
+    }
+}
@@ -0,0 +1,223 @@
+//! Stream wrapper that coalesces consecutive committed chains into batches.
+
+use std::{
+    future::Future,
+    pin::Pin,
+    sync::Arc,
+    task::{Context, Poll},
+    time::Duration,
+};
+
+use futures::{Stream, StreamExt};
+use reth_provider::Chain;
+use reth_tracing::tracing::trace;
+use tokio::time::{Instant, Sleep};
+
+use crate::ExExNotification;
+
+/// [`ExExNotification`] stream wrapper that coalesces consecutive
+/// [`ExExNotification::ChainCommitted`] notifications into a single notification with all of
+/// their blocks.
+///
+/// The current batch is yielded when any of the following happens:
+/// - The batch has reached the maximum number of blocks.
+///
+///   This is a soft limit: committed chains are never split, so a batch can exceed the maximum
+///   by up to the length of the last chain appended to it, minus one block. A single chain longer
+///   than the maximum is yielded as a batch of its own.
+/// - No new notification was received for the maximum wait duration, e.g. because the node has
+///   reached the tip of the chain.
+/// - An [`ExExNotification::ChainReorged`] or [`ExExNotification::ChainReverted`] notification is
+///   received. The batch is yielded first, followed by the notification itself.
+/// - A committed chain that doesn't connect to the tip of the batch is received. It starts a new
+///   batch.
+/// - The underlying stream is exhausted.
+#[derive(Debug)]
+pub struct ExExBatched<S> {
+    stream: S,
+    /// The number of blocks after which a batch is yielded. See [`ExExBatched`] for why a batch
+    /// can contain more blocks.
+    max_blocks: usize,
+    /// The maximum duration to wait for the next notification before yielding the batch.
+    max_wait: Duration,
+    /// The committed chain accumulated so far.
+    batch: Option<Chain>,
+    /// The notification to yield after the current batch.
+    next: Option<ExExNotification>,
+    /// The deadline for the next notification to arrive before the batch is yielded.
+    deadline: Pin<Box<Sleep>>,
+}
+
+impl<S> ExExBatched<S> {
+    /// Creates new [`ExExBatched`] stream wrapper.
+    pub fn new(stream: S, max_blocks: usize, max_wait: Duration) -> Self {
+        Self {
+            stream,
+            max_blocks,
+            max_wait,
+            batch: None,
+            next: None,
+            deadline: Box::pin(tokio::time::sleep(max_wait)),
+        }
+    }
+
+    /// Takes the current batch as a [`ExExNotification::ChainCommitted`] notification, if any.
+    fn take_batch(&mut self) -> Option<ExExNotification> {
+        let batch = self.batch.take()?;
+        trace!(target: "exex::stream::batched", range = ?batch.range(), "Yielding batch");
+        Some(ExExNotification::ChainCommitted { new: Arc::new(batch) })
+    }
+}
+
+impl<S> Stream for ExExBatched<S>
+where
+    S: Stream<Item = ExExNotification> + Unpin,
+{
+    type Item = ExExNotification;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let this = self.get_mut();
+
+        if let Some(notification) = this.next.take() {
+            return Poll::Ready(Some(notification))
+        }
+
+        loop {
+            let notification = match this.stream.poll_next_unpin(cx) {
+                Poll::Ready(Some(notification)) => notification,
+                Poll::Ready(None) => return Poll::Ready(this.take_batch()),
+                Poll::Pending => {
+                    if this.batch.is_some() && this.deadline.as_mut().poll(cx).is_ready() {
+                        return Poll::Ready(this.take_batch())
+                    }
+
+                    return Poll::Pending
+                }
+            };
+
+            let ExExNotification::ChainCommitted { new } = notification else {
+                // Yield the batch before the reorg or revert, if any
+                let Some(batch) = this.take_batch() else { return Poll::Ready(Some(notification)) };
+                this.next = Some(notification);
+                return Poll::Ready(Some(batch))
+            };
+            let new = Arc::unwrap_or_clone(new);
+
+            this.deadline.as_mut().reset(Instant::now() + this.max_wait);
+
+            match &mut this.batch {
+                Some(batch) if batch.tip().hash() == new.fork_block().hash => {
+                    batch.append_chain(new).expect("chains connect");
+                }
+                Some(_) => {
+                    // The new chain doesn't connect to the batch, so it starts a new one
+                    let batch = this.take_batch();
+                    this.batch = Some(new);
+                    return Poll::Ready(batch)
+                }
+                None => this.batch = Some(new),
+            }
+
+            if this.batch.as_ref().is_some_and(|batch| batch.len() >= this.max_blocks) {
+                return Poll::Ready(this.take_batch())
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{sync::Arc, time::Duration};
+
+    use eyre::OptionExt;
+    use futures::{stream, StreamExt};
+    use reth_provider::Chain;
+    use reth_testing_utils::generators::{self, random_block_range, BlockRangeParams};
+
+    use crate::{ExExNotification, ExExNotificationStreamExt};
+
+    #[tokio::test]
+    async fn test_batched() -> eyre::Result<()> {
+        let mut rng = generators::rng();
+
+        let blocks = random_block_range(&mut rng, 1..=3, BlockRangeParams::default())
+            .into_iter()
+            .map(|block| block.seal_with_senders().ok_or_eyre("failed to recover senders"))
+            .collect::<eyre::Result<Vec<_>>>()?;
+        let chain = |blocks: &[_]| Arc::new(Chain::new(blocks.to_vec(), Default::default(), None));
+
+        let committed = blocks
+            .iter()
+            .map(|block| ExExNotification::ChainCommitted { new: chain(&[block.clone()]) });
+        let reverted = ExExNotification::ChainReverted { old: chain(&blocks[2..]) };
+
+        let notifications = stream::iter(committed.chain([reverted.clone()]))
+            .batched(2, Duration::from_secs(1))
+            .collect::<Vec<_>>()
+            .await;
+
+        assert_eq!(
+            notifications,
+            vec![
+                ExExNotification::ChainCommitted { new: chain(&blocks[..2]) },
+                ExExNotification::ChainCommitted { new: chain(&blocks[2..]) },
+                reverted
+            ]
+        );
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_batched_max_blocks_soft_limit() -> eyre::Result<()> {
+        let mut rng = generators::rng();
+
+        let blocks = random_block_range(&mut rng, 1..=6, BlockRangeParams::default())
+            .into_iter()
+            .map(|block| block.seal_with_senders().ok_or_eyre("failed to recover senders"))
+            .collect::<eyre::Result<Vec<_>>>()?;
+        let chain = |blocks: &[_]| Arc::new(Chain::new(blocks.to_vec(), Default::default(), None));
+
+        // The chains are not split at the limit, so the first batch exceeds it, and the second
+        // chain alone is longer than the limit
+        let committed = [chain(&blocks[..1]), chain(&blocks[1..3]), chain(&blocks[3..])]
+            .into_iter()
+            .map(|new| ExExNotification::ChainCommitted { new });
+
+        let notifications =
+            stream::iter(committed).batched(2, Duration::from_secs(1)).collect::<Vec<_>>().await;
+
+        assert_eq!(
+            notifications,
+            vec![
+                ExExNotification::ChainCommitted { new: chain(&blocks[..3]) },
+                ExExNotification::ChainCommitted { new: chain(&blocks[3..]) },
+            ]
+        );
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_batched_max_wait() -> eyre::Result<()> {
+        let mut rng = generators::rng();
+
+        let block = random_block_range(&mut rng, 1..=1, BlockRangeParams::default())
+            .pop()
+            .ok_or_eyre("no blocks")?
+            .seal_with_senders()
+            .ok_or_eyre("failed to recover senders")?;
+        let committed = ExExNotification::ChainCommitted {
+            new: Arc::new(Chain::new(vec![block], Default::default(), None)),
+        };
+
+        // The underlying stream never ends, so the batch is only yielded after the max wait
+        let mut notifications = stream::iter([committed.clone()])
+            .chain(stream::pending())
+            .batched(10, Duration::from_millis(10));
+
+        assert_eq!(notifications.next().await, Some(committed));
+
+        Ok(())
+    }
+}
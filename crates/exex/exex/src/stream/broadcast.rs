@@ -0,0 +1,148 @@
+//! Stream wrapper that broadcasts notifications to multiple subscribers.
+
+use std::{
+    future::Future,
+    pin::Pin,
+    task::{ready, Context, Poll},
+};
+
+use futures::{Stream, StreamExt};
+use reth_tracing::tracing::warn;
+use tokio::sync::broadcast;
+use tokio_stream::wrappers::{errors::BroadcastStreamRecvError, BroadcastStream};
+
+use crate::ExExNotification;
+
+/// Future that drives an [`ExExNotification`] stream to completion, broadcasting each notification
+/// to all [`ExExBroadcastSubscriber`]s.
+///
+/// Subscribers only receive the notifications broadcasted after they subscribed, so they should be
+/// created with [`ExExBroadcast::subscribe`] before the future is polled, e.g. spawned.
+///
+/// Notifications are buffered in a bounded channel. If a subscriber is slow to consume them, the
+/// oldest notifications it didn't receive yet are dropped. See [`ExExBroadcastSubscriber::lagged`].
+#[derive(Debug)]
+pub struct ExExBroadcast<S> {
+    stream: S,
+    /// The sender of the channel the notifications are broadcasted to.
+    sender: broadcast::Sender<ExExNotification>,
+}
+
+impl<S> ExExBroadcast<S> {
+    /// Creates new [`ExExBroadcast`] stream wrapper, buffering up to `capacity` notifications for
+    /// each subscriber.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `capacity` is zero.
+    pub fn new(stream: S, capacity: usize) -> Self {
+        let (sender, _) = broadcast::channel(capacity);
+        Self { stream, sender }
+    }
+
+    /// Returns a new subscriber receiving the notifications broadcasted from now on.
+    pub fn subscribe(&self) -> ExExBroadcastSubscriber {
+        ExExBroadcastSubscriber { stream: BroadcastStream::new(self.sender.subscribe()), lagged: 0 }
+    }
+}
+
+impl<S> Future for ExExBroadcast<S>
+where
+    S: Stream<Item = ExExNotification> + Unpin,
+{
+    type Output = ();
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let this = self.get_mut();
+
+        while let Some(notification) = ready!(this.stream.poll_next_unpin(cx)) {
+            // An error only means that there are no subscribers at the moment
+            let _ = this.sender.send(notification);
+        }
+
+        Poll::Ready(())
+    }
+}
+
+/// [`ExExNotification`] stream of a subscriber to an [`ExExBroadcast`].
+///
+/// The stream ends when the [`ExExBroadcast`] future completes, after all notifications buffered
+/// for the subscriber were yielded.
+#[derive(Debug)]
+pub struct ExExBroadcastSubscriber {
+    stream: BroadcastStream<ExExNotification>,
+    /// The total number of notifications dropped because the subscriber lagged behind.
+    lagged: u64,
+}
+
+impl ExExBroadcastSubscriber {
+    /// Returns the total number of notifications the subscriber didn't receive, because it lagged
+    /// behind by more than the capacity of the [`ExExBroadcast`].
+    ///
+    /// The dropped notifications can contain reverted chains, so a subscriber that lagged can't
+    /// rely on its state being consistent with the host chain anymore.
+    pub const fn lagged(&self) -> u64 {
+        self.lagged
+    }
+}
+
+impl Stream for ExExBroadcastSubscriber {
+    type Item = ExExNotification;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let this = self.get_mut();
+
+        loop {
+            return match ready!(this.stream.poll_next_unpin(cx)) {
+                Some(Ok(notification)) => Poll::Ready(Some(notification)),
+                Some(Err(BroadcastStreamRecvError::Lagged(skipped))) => {
+                    warn!(target: "exex::stream::broadcast", skipped, "Subscriber lagged behind, skipping notifications");
+                    this.lagged += skipped;
+                    continue
+                }
+                None => Poll::Ready(None),
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use futures::{stream, StreamExt};
+    use reth_primitives::SealedBlockWithSenders;
+    use reth_provider::Chain;
+
+    use crate::{ExExNotification, ExExNotificationStreamExt};
+
+    fn committed(number: u64) -> ExExNotification {
+        let mut block = SealedBlockWithSenders::default();
+        block.block.header.set_block_number(number);
+        ExExNotification::ChainCommitted {
+            new: Arc::new(Chain::new(vec![block], Default::default(), None)),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_broadcast() {
+        let notifications = (0..3).map(committed).collect::<Vec<_>>();
+
+        let broadcast = stream::iter(notifications.clone()).broadcast(2);
+        let subscriber_1 = broadcast.subscribe();
+        let mut subscriber_2 = broadcast.subscribe();
+
+        // Broadcast all notifications before the subscribers consume any of them
+        broadcast.await;
+
+        // Both subscribers lagged behind by one notification, so the oldest one was dropped
+        assert_eq!(subscriber_2.next().await, Some(notifications[1].clone()));
+        assert_eq!(subscriber_2.lagged(), 1);
+        assert_eq!(subscriber_2.next().await, Some(notifications[2].clone()));
+        assert_eq!(subscriber_2.next().await, None);
+
+        // The subscribers are independent, so the second one consuming the notifications doesn't
+        // affect the first one
+        assert_eq!(subscriber_1.collect::<Vec<_>>().await, notifications[1..]);
+    }
+}
@@ -0,0 +1,158 @@
+//! Stream wrapper that flattens notifications into the logs they contain.
+
+use std::{
+    collections::VecDeque,
+    pin::Pin,
+    task::{ready, Context, Poll},
+};
+
+use alloy_eips::BlockNumHash;
+use alloy_primitives::Log;
+use futures::{Stream, StreamExt};
+use reth_provider::Chain;
+
+use crate::ExExNotification;
+
+/// A log contained in an [`ExExNotification`], yielded by [`ExExLogs`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ExExLog {
+    /// The block containing the log.
+    pub block: BlockNumHash,
+    /// The log.
+    pub log: Log,
+    /// Whether the log was removed, i.e. the block containing it was reverted.
+    pub removed: bool,
+}
+
+/// [`ExExNotification`] stream wrapper that yields the logs of the reverted and committed chains,
+/// instead of the notifications.
+///
+/// For each notification, the logs of the reverted chain, if any, are yielded first with
+/// [`ExExLog::removed`] set, in reverse order, i.e. starting from the last log of the tip block.
+/// Then, the logs of the committed chain, if any, are yielded in order.
+///
+/// Only the logs are cloned, not the receipts containing them.
+#[derive(Debug)]
+pub struct ExExLogs<S> {
+    stream: S,
+    /// The logs of the last notification that are not yielded yet.
+    pending: VecDeque<ExExLog>,
+}
+
+impl<S> ExExLogs<S> {
+    /// Creates new [`ExExLogs`] stream wrapper.
+    pub const fn new(stream: S) -> Self {
+        Self { stream, pending: VecDeque::new() }
+    }
+
+    /// Queues the logs of the given chain, in reverse order if `removed` is set.
+    fn queue_logs(&mut self, chain: &Chain, removed: bool) {
+        let logs = chain.blocks_and_receipts().flat_map(|(block, receipts)| {
+            let block = BlockNumHash::new(block.number, block.hash());
+            receipts.iter().flatten().flat_map(|receipt| &receipt.logs).map(move |log| ExExLog {
+                block,
+                log: log.clone(),
+                removed,
+            })
+        });
+
+        if removed {
+            self.pending.extend(logs.collect::<Vec<_>>().into_iter().rev());
+        } else {
+            self.pending.extend(logs);
+        }
+    }
+}
+
+impl<S> Stream for ExExLogs<S>
+where
+    S: Stream<Item = ExExNotification> + Unpin,
+{
+    type Item = ExExLog;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let this = self.get_mut();
+
+        loop {
+            if let Some(log) = this.pending.pop_front() {
+                return Poll::Ready(Some(log))
+            }
+
+            let Some(notification) = ready!(this.stream.poll_next_unpin(cx)) else {
+                return Poll::Ready(None)
+            };
+
+            if let Some(reverted) = notification.reverted_chain() {
+                this.queue_logs(&reverted, true);
+            }
+            if let Some(committed) = notification.committed_chain() {
+                this.queue_logs(&committed, false);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use alloy_eips::BlockNumHash;
+    use alloy_primitives::{Address, Bytes, Log, B256};
+    use futures::{stream, StreamExt};
+    use reth_primitives::{Receipt, Receipts, SealedBlockWithSenders};
+    use reth_provider::{Chain, ExecutionOutcome};
+
+    use crate::{ExExLog, ExExNotification, ExExNotificationStreamExt};
+
+    fn log(topic: u8) -> Log {
+        Log::new_unchecked(Address::ZERO, vec![B256::with_last_byte(topic)], Bytes::new())
+    }
+
+    fn chain_with_logs(number: u64, logs: Vec<Log>) -> (Arc<Chain>, BlockNumHash) {
+        let mut block = SealedBlockWithSenders::default();
+        block.block.header.set_block_number(number);
+        let num_hash = BlockNumHash::new(number, block.hash());
+
+        let receipt = Receipt { logs, ..Default::default() };
+
+        let chain = Chain::new(
+            vec![block],
+            ExecutionOutcome::new(
+                Default::default(),
+                Receipts::from(vec![receipt]),
+                number,
+                vec![],
+            ),
+            None,
+        );
+        (Arc::new(chain), num_hash)
+    }
+
+    #[tokio::test]
+    async fn test_logs_only() {
+        let (committed, committed_block) = chain_with_logs(1, vec![log(1), log(2)]);
+        let (old, old_block) = chain_with_logs(1, vec![log(1), log(2)]);
+        let (new, new_block) = chain_with_logs(1, vec![log(3)]);
+
+        let logs = stream::iter([
+            ExExNotification::ChainCommitted { new: committed },
+            ExExNotification::ChainReorged { old, new },
+        ])
+        .logs_only()
+        .collect::<Vec<_>>()
+        .await;
+
+        // The logs of the reverted chain are removed in reverse order, before the logs of the new
+        // chain are yielded
+        assert_eq!(
+            logs,
+            vec![
+                ExExLog { block: committed_block, log: log(1), removed: false },
+                ExExLog { block: committed_block, log: log(2), removed: false },
+                ExExLog { block: old_block, log: log(2), removed: true },
+                ExExLog { block: old_block, log: log(1), removed: true },
+                ExExLog { block: new_block, log: log(3), removed: false },
+            ]
+        );
+    }
+}
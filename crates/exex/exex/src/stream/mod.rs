@@ -0,0 +1,86 @@
+//! Collection of stream utilities for [`ExExNotification`] streams.
+
+use std::{collections::HashSet, time::Duration};
+
+use alloy_primitives::{Address, B256};
+use futures::Stream;
+
+use crate::ExExNotification;
+
+mod address_filter;
+pub use address_filter::ExExAddressFilter;
+
+mod batched;
+pub use batched::ExExBatched;
+
+mod broadcast;
+pub use broadcast::{ExExBroadcast, ExExBroadcastSubscriber};
+
+mod logs;
+pub use logs::{ExExLog, ExExLogs};
+
+mod topic_filter;
+pub use topic_filter::ExExTopicFilter;
+
+/// The collection of stream extensions for [`ExExNotification`] streams, such as
+/// [`ExExNotifications`](crate::ExExNotifications).
+pub trait ExExNotificationStreamExt: Stream<Item = ExExNotification> {
+    /// Only yields committed chains that touch any of the given addresses. See
+    /// [`ExExAddressFilter`] for what it means for a chain to touch an address.
+    ///
+    /// Notifications that revert a chain are always yielded.
+    fn with_address_filter(self, addresses: HashSet<Address>) -> ExExAddressFilter<Self>
+    where
+        Self: Sized,
+    {
+        ExExAddressFilter::new(self, addresses)
+    }
+
+    /// Only yields committed chains that contain a log with any of the given topics. See
+    /// [`ExExTopicFilter`] for what it means for a log to match a topic.
+    ///
+    /// Notifications that revert a chain are always yielded.
+    fn with_topic_filter(self, topics: HashSet<B256>) -> ExExTopicFilter<Self>
+    where
+        Self: Sized,
+    {
+        ExExTopicFilter::new(self, topics)
+    }
+
+    /// Coalesces consecutive committed chains into batches, yielding a batch once it has
+    /// `max_blocks` blocks or more. See [`ExExBatched`] for when else a batch is yielded, and why
+    /// `max_blocks` is a soft limit.
+    ///
+    /// Notifications that revert a chain are never batched and are yielded in order.
+    fn batched(self, max_blocks: usize, max_wait: Duration) -> ExExBatched<Self>
+    where
+        Self: Sized,
+    {
+        ExExBatched::new(self, max_blocks, max_wait)
+    }
+
+    /// Yields the logs of the reverted and committed chains instead of the notifications. See
+    /// [`ExExLogs`] for the order in which the logs are yielded.
+    fn logs_only(self) -> ExExLogs<Self>
+    where
+        Self: Sized,
+    {
+        ExExLogs::new(self)
+    }
+
+    /// Broadcasts the notifications to multiple subscribers, buffering up to `capacity`
+    /// notifications for each of them. See [`ExExBroadcast`] for how to subscribe and what
+    /// happens when a subscriber lags behind.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `capacity` is zero.
+    fn broadcast(self, capacity: usize) -> ExExBroadcast<Self>
+    where
+        Self: Sized,
+    {
+        ExExBroadcast::new(self, capacity)
+    }
+}
+
+impl<S> ExExNotificationStreamExt for S where S: Stream<Item = ExExNotification> {}
@@ -0,0 +1,127 @@
+//! Stream wrapper that filters notifications by the topics of the logs they contain.
+
+use std::{
+    collections::HashSet,
+    pin::Pin,
+    task::{ready, Context, Poll},
+};
+
+use alloy_primitives::B256;
+use futures::{Stream, StreamExt};
+use reth_provider::Chain;
+use reth_tracing::tracing::trace;
+
+use crate::ExExNotification;
+
+/// [`ExExNotification`] stream wrapper that only yields committed chains containing a log with any
+/// of the given topics.
+///
+/// A log matches if its first topic, i.e. the event signature hash for non-anonymous events, is
+/// one of the given topics.
+///
+/// [`ExExNotification::ChainReorged`] and [`ExExNotification::ChainReverted`] notifications are
+/// always yielded, regardless of the logs they contain, so that the consumer can unwind its state
+/// for the reverted blocks.
+#[derive(Debug)]
+pub struct ExExTopicFilter<S> {
+    stream: S,
+    /// The topics to filter the committed chains by.
+    topics: HashSet<B256>,
+}
+
+impl<S> ExExTopicFilter<S> {
+    /// Creates new [`ExExTopicFilter`] stream wrapper.
+    pub const fn new(stream: S, topics: HashSet<B256>) -> Self {
+        Self { stream, topics }
+    }
+
+    /// Returns `true` if any of the receipts in the chain contains a log with any of the topics.
+    fn contains_topics(&self, chain: &Chain) -> bool {
+        chain.block_receipts_iter().flatten().flatten().any(|receipt| {
+            receipt
+                .logs
+                .iter()
+                .any(|log| log.topics().first().is_some_and(|topic| self.topics.contains(topic)))
+        })
+    }
+}
+
+impl<S> Stream for ExExTopicFilter<S>
+where
+    S: Stream<Item = ExExNotification> + Unpin,
+{
+    type Item = ExExNotification;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let this = self.get_mut();
+
+        loop {
+            let Some(notification) = ready!(this.stream.poll_next_unpin(cx)) else {
+                return Poll::Ready(None)
+            };
+
+            if let ExExNotification::ChainCommitted { new } = &notification {
+                if !this.contains_topics(new) {
+                    trace!(target: "exex::stream::topic_filter", range = ?new.range(), "Skipping committed chain");
+                    continue
+                }
+            }
+
+            return Poll::Ready(Some(notification))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use alloy_primitives::{Address, Bytes, Log, B256};
+    use futures::{stream, StreamExt};
+    use reth_primitives::{Receipt, Receipts, SealedBlockWithSenders};
+    use reth_provider::{Chain, ExecutionOutcome};
+
+    use crate::{ExExNotification, ExExNotificationStreamExt};
+
+    fn chain_with_log_topic(number: u64, topic: B256) -> Arc<Chain> {
+        let mut block = SealedBlockWithSenders::default();
+        block.block.header.set_block_number(number);
+
+        let receipt = Receipt {
+            logs: vec![Log::new_unchecked(Address::ZERO, vec![topic], Bytes::new())],
+            ..Default::default()
+        };
+
+        Arc::new(Chain::new(
+            vec![block],
+            ExecutionOutcome::new(
+                Default::default(),
+                Receipts::from(vec![receipt]),
+                number,
+                vec![],
+            ),
+            None,
+        ))
+    }
+
+    #[tokio::test]
+    async fn test_topic_filter() {
+        let topic = B256::with_last_byte(1);
+
+        let committed_1 = ExExNotification::ChainCommitted { new: chain_with_log_topic(1, topic) };
+        let committed_2 = ExExNotification::ChainCommitted {
+            new: chain_with_log_topic(2, B256::with_last_byte(2)),
+        };
+        let reverted_2 = ExExNotification::ChainReverted {
+            old: chain_with_log_topic(2, B256::with_last_byte(2)),
+        };
+
+        let notifications = stream::iter([committed_1.clone(), committed_2, reverted_2.clone()])
+            .with_topic_filter([topic].into())
+            .collect::<Vec<_>>()
+            .await;
+
+        // The second committed chain doesn't contain the topic, but the revert is still delivered
+        assert_eq!(notifications, vec![committed_1, reverted_2]);
+    }
+}
@@ -85,6 +85,29 @@ impl BlockCache {
         self.committed_blocks.get(block_hash).map(|entry| entry.0)
     }
 
+    /// Returns the lowest file ID of the notifications with the committed chain containing the
+    /// given block number.
+    pub(super) fn get_first_file_id_by_committed_block_number(
+        &self,
+        block_number: BlockNumber,
+    ) -> Option<u32> {
+        self.committed_blocks
+            .values()
+            .filter(|(_, cached_block)| cached_block.block.number == block_number)
+            .map(|(file_id, _)| *file_id)
+            .min()
+    }
+
+    /// Returns the lowest file ID in the cache.
+    pub(super) fn first_file_id(&self) -> Option<u32> {
+        self.notification_max_blocks.iter().map(|Reverse((_, file_id))| *file_id).min()
+    }
+
+    /// Returns the lowest committed block number in the cache.
+    pub(super) fn lowest_committed_block_number(&self) -> Option<BlockNumber> {
+        self.committed_blocks.values().map(|(_, cached_block)| cached_block.block.number).min()
+    }
+
     /// Inserts the blocks from the notification into the cache with the given file ID.
     pub(super) fn insert_notification_blocks_with_file_id(
         &mut self,
@@ -8,6 +8,7 @@ mod metrics;
 use metrics::Metrics;
 
 use std::{
+    ops::RangeInclusive,
     path::Path,
     sync::{
         atomic::{AtomicU32, Ordering},
@@ -16,10 +17,11 @@ use std::{
 };
 
 use alloy_eips::BlockNumHash;
-use alloy_primitives::B256;
+use alloy_primitives::{BlockNumber, B256};
 use parking_lot::{RwLock, RwLockReadGuard};
 use reth_exex_types::ExExNotification;
 use reth_tracing::tracing::{debug, instrument};
+use tokio::sync::mpsc;
 
 /// WAL is a write-ahead log (WAL) that stores the notifications sent to ExExes.
 ///
@@ -66,6 +68,73 @@ impl Wal {
     ) -> eyre::Result<Box<dyn Iterator<Item = eyre::Result<ExExNotification>> + '_>> {
         self.inner.iter_notifications()
     }
+
+    /// Returns the range of file IDs from the first notification with the committed chain
+    /// containing the given block number to the last notification committed to the WAL, if
+    /// there's such a notification.
+    pub(crate) fn file_ids_from_committed_block(
+        &self,
+        block_number: BlockNumber,
+    ) -> Option<RangeInclusive<u32>> {
+        let first_file_id =
+            self.inner.block_cache().get_first_file_id_by_committed_block_number(block_number)?;
+        Some(first_file_id..=self.inner.last_file_id()?)
+    }
+
+    /// Returns the range of file IDs from the first to the last notification in the WAL, if it's
+    /// not empty.
+    pub(crate) fn file_ids(&self) -> Option<RangeInclusive<u32>> {
+        let first_file_id = self.inner.block_cache().first_file_id()?;
+        Some(first_file_id..=self.inner.last_file_id()?)
+    }
+
+    /// Returns the file ID of the last notification with the committed chain containing the given
+    /// block hash, if it exists.
+    pub(crate) fn file_id_by_committed_block_hash(&self, block_hash: &B256) -> Option<u32> {
+        self.inner.block_cache().get_file_id_by_committed_block_hash(block_hash)
+    }
+
+    /// Reads the notification with the given file ID, if it exists.
+    pub(crate) fn read_notification(&self, file_id: u32) -> eyre::Result<Option<ExExNotification>> {
+        self.inner
+            .storage
+            .read_notification(file_id)
+            .map(|entry| entry.map(|(notification, _)| notification))
+    }
+
+    /// Returns the lowest committed block number in the WAL.
+    pub(crate) fn lowest_committed_block_number(&self) -> Option<BlockNumber> {
+        self.inner.block_cache().lowest_committed_block_number()
+    }
+
+    /// Reads the notifications with the given file IDs in a blocking task, and sends them in
+    /// order over the returned channel, which holds at most `capacity` notifications not yet
+    /// received.
+    ///
+    /// The notifications removed from the WAL in the meantime by [`Self::finalize`] are skipped.
+    /// The reading stops after the first error, or when the receiver is dropped.
+    pub(crate) fn read_notifications(
+        &self,
+        file_ids: RangeInclusive<u32>,
+        capacity: usize,
+    ) -> mpsc::Receiver<eyre::Result<ExExNotification>> {
+        let (tx, rx) = mpsc::channel(capacity);
+        let inner = self.inner.clone();
+        tokio::task::spawn_blocking(move || {
+            for file_id in file_ids {
+                let notification = match inner.storage.read_notification(file_id) {
+                    Ok(Some((notification, _))) => Ok(notification),
+                    Ok(None) => continue,
+                    Err(err) => Err(err),
+                };
+                let is_err = notification.is_err();
+                if tx.blocking_send(notification).is_err() || is_err {
+                    break
+                }
+            }
+        });
+        rx
+    }
 }
 
 /// Inner type for the WAL.
@@ -95,6 +164,11 @@ impl WalInner {
         self.block_cache.read()
     }
 
+    /// Returns the ID of the last file committed to the WAL, if any.
+    fn last_file_id(&self) -> Option<u32> {
+        self.next_file_id.load(Ordering::Relaxed).checked_sub(1)
+    }
+
     /// Fills the block cache with the notifications from the storage.
     #[instrument(skip(self))]
     fn fill_block_cache(&mut self) -> eyre::Result<()> {
@@ -198,7 +272,7 @@ impl WalInner {
 }
 
 /// A read-only handle to the WAL that can be shared.
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub struct WalHandle {
     wal: Arc<WalInner>,
 }
@@ -515,4 +589,43 @@ mod tests {
 
         Ok(())
     }
+
+    #[tokio::test]
+    async fn test_read_notifications_from_committed_block() -> eyre::Result<()> {
+        let mut rng = generators::rng();
+
+        let temp_dir = tempfile::tempdir()?;
+        let wal = Wal::new(&temp_dir)?;
+
+        let notifications = random_block_range(&mut rng, 0..=3, BlockRangeParams::default())
+            .into_iter()
+            .map(|block| {
+                let block = block.seal_with_senders().ok_or_eyre("failed to recover senders")?;
+                Ok(ExExNotification::ChainCommitted {
+                    new: Arc::new(Chain::new(vec![block], Default::default(), None)),
+                })
+            })
+            .collect::<eyre::Result<Vec<_>>>()?;
+        for notification in &notifications {
+            wal.commit(notification)?;
+        }
+
+        assert_eq!(wal.lowest_committed_block_number(), Some(0));
+        assert_eq!(wal.file_ids_from_committed_block(4), None);
+        let file_ids = wal.file_ids_from_committed_block(1).ok_or_eyre("block 1 not in WAL")?;
+        assert_eq!(file_ids, 1..=3);
+
+        // The notifications removed from the WAL in the meantime are skipped
+        wal.finalize((1, notifications[1].committed_chain().unwrap().tip().hash()).into())?;
+
+        // The notifications are read one at a time, and received in order
+        let mut rx = wal.read_notifications(file_ids, 1);
+        let mut read = Vec::new();
+        while let Some(notification) = rx.recv().await {
+            read.push(notification?);
+        }
+        assert_eq!(read, notifications[2..]);
+
+        Ok(())
+    }
 }
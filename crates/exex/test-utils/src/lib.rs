@@ -27,7 +27,9 @@ use reth_db_common::init::init_genesis;
 use reth_ethereum_engine_primitives::EthereumEngineValidator;
 use reth_evm::test_utils::MockExecutorProvider;
 use reth_execution_types::Chain;
-use reth_exex::{ExExContext, ExExEvent, ExExNotification, ExExNotifications, Wal};
+use reth_exex::{
+    ExExContext, ExExEvent, ExExNotification, ExExNotifications, ExExReplayRequest, Wal,
+};
 use reth_network::{config::SecretKey, NetworkConfigBuilder, NetworkManager};
 use reth_node_api::{
     FullNodeTypes, FullNodeTypesAdapter, NodeTypes, NodeTypesWithDBAdapter, NodeTypesWithEngine,
@@ -58,6 +60,7 @@ use reth_transaction_pool::test_utils::{testing_pool, TestPool};
 
 use thiserror::Error;
 use tokio::sync::mpsc::{Sender, UnboundedReceiver};
+use tokio_util::sync::CancellationToken;
 
 /// A test [`PoolBuilder`] that builds a [`TestPool`].
 #[derive(Debug, Default, Clone, Copy)]
@@ -180,8 +183,13 @@ pub struct TestExExHandle {
     pub provider_factory: ProviderFactory<NodeTypesWithDBAdapter<TestNode, TmpDB>>,
     /// Channel for receiving events from the Execution Extension
     pub events_rx: UnboundedReceiver<ExExEvent>,
+    /// Channel for receiving replay requests from the Execution Extension
+    pub replay_requests_rx: UnboundedReceiver<ExExReplayRequest>,
     /// Channel for sending notifications to the Execution Extension
     pub notifications_tx: Sender<ExExNotification>,
+    /// Signal for stopping the Execution Extension, as if it was unregistered from the `ExEx`
+    /// manager
+    pub stop_signal: CancellationToken,
     /// Node task manager
     pub tasks: TaskManager,
 }
@@ -306,6 +314,8 @@ pub async fn test_exex_context_with_chain_spec(
     };
 
     let (events_tx, events_rx) = tokio::sync::mpsc::unbounded_channel();
+    let (replay_requests_tx, replay_requests_rx) = tokio::sync::mpsc::unbounded_channel();
+    let stop_signal = CancellationToken::new();
     let (notifications_tx, notifications_rx) = tokio::sync::mpsc::channel(1);
     let notifications = ExExNotifications::new(
         head,
@@ -321,11 +331,24 @@ pub async fn test_exex_context_with_chain_spec(
         config: NodeConfig::test(),
         reth_config: reth_config::Config::default(),
         events: events_tx,
+        replay_requests: replay_requests_tx,
+        stop_signal: stop_signal.clone(),
         notifications,
         components,
     };
 
-    Ok((ctx, TestExExHandle { genesis, provider_factory, events_rx, notifications_tx, tasks }))
+    Ok((
+        ctx,
+        TestExExHandle {
+            genesis,
+            provider_factory,
+            events_rx,
+            replay_requests_rx,
+            notifications_tx,
+            stop_signal,
+            tasks,
+        },
+    ))
 }
 
 /// Creates a new [`ExExContext`] with (mainnet)[`MAINNET`] chain spec.
@@ -379,4 +402,21 @@ mod tests {
     async fn check_test_context_creation() {
         let _ = test_exex_context().await.unwrap();
     }
+
+    #[tokio::test]
+    async fn check_on_shutdown_signal() {
+        let (ctx, handle) = test_exex_context().await.unwrap();
+        let on_shutdown = ctx.on_shutdown_signal();
+        handle.tasks.graceful_shutdown();
+        on_shutdown.await;
+    }
+
+    #[tokio::test]
+    async fn check_notify_finished_height() {
+        let (ctx, mut handle) = test_exex_context().await.unwrap();
+
+        let height = handle.genesis.num_hash();
+        ctx.notify_finished_height(height).unwrap();
+        handle.assert_event_finished_height(height).unwrap();
+    }
 }
@@ -1,5 +1,6 @@
 use std::sync::Arc;
 
+use alloy_eips::BlockNumHash;
 use reth_chain_state::CanonStateNotification;
 use reth_execution_types::Chain;
 
@@ -45,6 +46,20 @@ impl ExExNotification {
         }
     }
 
+    /// Returns the [`ExExReorgInfo`] of the [`Self::ChainReorged`] and [`Self::ChainReverted`]
+    /// variants, if any.
+    pub fn reorg_info(&self) -> Option<ExExReorgInfo> {
+        match self {
+            Self::ChainReorged { old, new: _ } | Self::ChainReverted { old } => {
+                Some(ExExReorgInfo {
+                    reverted_blocks: old.len() as u64,
+                    fork_point: old.fork_block(),
+                })
+            }
+            Self::ChainCommitted { .. } => None,
+        }
+    }
+
     /// Converts the notification into a notification that is the inverse of the original one.
     ///
     /// - For [`Self::ChainCommitted`], it's [`Self::ChainReverted`].
@@ -60,6 +75,16 @@ impl ExExNotification {
     }
 }
 
+/// Information about the blocks reverted by an [`ExExNotification`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ExExReorgInfo {
+    /// The number of reverted blocks.
+    pub reverted_blocks: u64,
+    /// The common ancestor of the old and the new chains, i.e. the last block that was not
+    /// reverted.
+    pub fork_point: BlockNumHash,
+}
+
 impl From<CanonStateNotification> for ExExNotification {
     fn from(notification: CanonStateNotification) -> Self {
         match notification {
@@ -208,3 +233,51 @@ pub(super) mod serde_bincode_compat {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use alloy_eips::BlockNumHash;
+    use alloy_primitives::B256;
+    use reth_execution_types::Chain;
+    use reth_primitives::{Header, SealedBlock, SealedBlockWithSenders, SealedHeader};
+
+    use super::{ExExNotification, ExExReorgInfo};
+
+    fn block(number: u64, parent_hash: B256) -> SealedBlockWithSenders {
+        SealedBlockWithSenders {
+            block: SealedBlock {
+                header: SealedHeader::new(
+                    Header { number, parent_hash, ..Default::default() },
+                    B256::with_last_byte(number as u8),
+                ),
+                body: Default::default(),
+            },
+            senders: Vec::new(),
+        }
+    }
+
+    #[test]
+    fn test_reorg_info() {
+        let fork_point = BlockNumHash::new(9, B256::with_last_byte(9));
+        let old = Arc::new(Chain::new(
+            vec![block(10, fork_point.hash), block(11, B256::with_last_byte(10))],
+            Default::default(),
+            Default::default(),
+        ));
+        let new = Arc::new(Chain::new(
+            vec![block(10, fork_point.hash)],
+            Default::default(),
+            Default::default(),
+        ));
+
+        let expected = ExExReorgInfo { reverted_blocks: 2, fork_point };
+        assert_eq!(
+            ExExNotification::ChainReorged { old: old.clone(), new: new.clone() }.reorg_info(),
+            Some(expected)
+        );
+        assert_eq!(ExExNotification::ChainReverted { old }.reorg_info(), Some(expected));
+        assert_eq!(ExExNotification::ChainCommitted { new }.reorg_info(), None);
+    }
+}
@@ -0,0 +1,39 @@
+use alloy_eips::BlockNumHash;
+
+/// The status of an `ExEx` registered in the node.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase"))]
+pub struct ExExStatus {
+    /// The ID of the `ExEx`.
+    pub id: String,
+    /// The last finished height emitted by the `ExEx`.
+    ///
+    /// If this is `None`, the `ExEx` has not emitted a `FinishedHeight` event.
+    pub finished_height: Option<BlockNumHash>,
+    /// The state of the `ExEx`.
+    pub state: ExExState,
+}
+
+/// The state of an `ExEx` registered in the node.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase"))]
+pub enum ExExState {
+    /// The `ExEx` is running and receives notifications.
+    Running,
+    /// The `ExEx` has exited, i.e. it stopped receiving notifications.
+    Exited,
+    /// The `ExEx` was terminated by the node, e.g. because it exceeded the max number of buffered
+    /// notifications.
+    Terminated,
+    /// The `ExEx` returned an error, with the given message, and was removed from the node.
+    Errored(String),
+}
+
+impl ExExState {
+    /// Returns `true` if the `ExEx` is running.
+    pub const fn is_running(&self) -> bool {
+        matches!(self, Self::Running)
+    }
+}
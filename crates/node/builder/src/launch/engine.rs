@@ -138,7 +138,7 @@ where
             .with_components(components_builder, on_component_initialized).await?;
 
         // spawn exexs
-        let exex_manager_handle = ExExLauncher::new(
+        let exex_registry = ExExLauncher::new(
             ctx.head(),
             ctx.node_adapter().clone(),
             installed_exex,
@@ -146,6 +146,8 @@ where
         )
         .launch()
         .await?;
+        let exex_manager_handle =
+            exex_registry.as_ref().map(|registry| registry.manager_handle().clone());
 
         // create pipeline
         let network_client = ctx.components().network().fetch_client().await?;
@@ -288,6 +290,7 @@ where
             ctx.node_config(),
             jwt_secret,
             rpc,
+            exex_manager_handle.as_ref(),
         )
         .await?;
 
@@ -402,6 +405,7 @@ where
             rpc_registry,
             config: ctx.node_config().clone(),
             data_dir: ctx.data_dir().clone(),
+            exex_registry,
         };
         // Notify on node started
         on_node_started.on_event(full_node.clone())?;
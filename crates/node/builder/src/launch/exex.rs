@@ -1,20 +1,32 @@
 //! Support for launching execution extensions.
 
-use std::{fmt, fmt::Debug};
+use std::{fmt, fmt::Debug, sync::Arc, time::Duration};
 
-use futures::future;
+use futures::{future, FutureExt};
 use reth_chain_state::ForkChoiceSubscriptions;
 use reth_chainspec::EthChainSpec;
 use reth_exex::{
-    ExExContext, ExExHandle, ExExManager, ExExManagerHandle, Wal, DEFAULT_EXEX_MANAGER_CAPACITY,
+    ExExContext, ExExHandle, ExExManager, ExExManagerHandle, ExExOverflowPolicy,
+    ExExRegistrationError, ExExStatus, ExExWatchdogAction, FinishedHeightsStorage, Wal, WalHandle,
+    DEFAULT_EXEX_MANAGER_CAPACITY, DEFAULT_EXEX_MANAGER_DRAIN_TIMEOUT,
 };
 use reth_node_api::{FullNodeComponents, NodeTypes};
+use reth_node_core::args::{
+    ExExOverflowPolicy as ExExOverflowPolicyArg, ExExWatchdogAction as ExExWatchdogActionArg,
+};
 use reth_primitives::Head;
-use reth_provider::CanonStateSubscriptions;
-use reth_tracing::tracing::{debug, info};
+use reth_provider::{
+    BlockHashReader, CanonStateSubscriptions, DatabaseProviderFactory, HeaderProvider,
+    StageCheckpointReader,
+};
+use reth_tasks::TaskExecutor;
+use reth_tracing::tracing::{debug, error, info};
 use tracing::Instrument;
 
-use crate::{common::WithConfigs, exex::BoxedLaunchExEx};
+use crate::{
+    common::WithConfigs,
+    exex::{BoxedLaunchExEx, LaunchExEx},
+};
 
 /// Can launch execution extensions.
 pub struct ExExLauncher<Node: FullNodeComponents> {
@@ -37,9 +49,9 @@ impl<Node: FullNodeComponents + Clone> ExExLauncher<Node> {
 
     /// Launches all execution extensions.
     ///
-    /// Spawns all extensions and returns the handle to the exex manager if any extensions are
-    /// installed.
-    pub async fn launch(self) -> eyre::Result<Option<ExExManagerHandle>> {
+    /// Spawns all extensions and returns the [`ExExRegistry`] of the running node if any
+    /// extensions are installed.
+    pub async fn launch(self) -> eyre::Result<Option<ExExRegistry<Node>>> {
         let Self { head, extensions, components, config_container } = self;
 
         if extensions.is_empty() {
@@ -47,77 +59,84 @@ impl<Node: FullNodeComponents + Clone> ExExLauncher<Node> {
             return Ok(None)
         }
 
-        let exex_wal = Wal::new(
-            config_container
-                .config
-                .datadir
-                .clone()
-                .resolve_datadir(config_container.config.chain.chain())
-                .exex_wal(),
-        )?;
+        let datadir = config_container
+            .config
+            .datadir
+            .clone()
+            .resolve_datadir(config_container.config.chain.chain());
+        let exex_wal = Wal::new(datadir.exex_wal())?;
+        let wal_handle = exex_wal.handle();
+        let finished_heights_storage =
+            FinishedHeightsStorage::new(datadir.exex_finished_heights())?;
 
         let mut exex_handles = Vec::with_capacity(extensions.len());
         let mut exexes = Vec::with_capacity(extensions.len());
 
         for (id, exex) in extensions {
-            // create a new exex handle
-            let (handle, events, notifications) = ExExHandle::new(
-                id.clone(),
-                head,
-                components.provider().clone(),
-                components.block_executor().clone(),
-                exex_wal.handle(),
-            );
+            let (handle, context) =
+                new_exex(id.clone(), head, &components, &config_container, wal_handle.clone());
             exex_handles.push(handle);
-
-            // create the launch context for the exex
-            let context = ExExContext {
-                head,
-                config: config_container.config.clone(),
-                reth_config: config_container.toml_config.clone(),
-                components: components.clone(),
-                events,
-                notifications,
-            };
-
-            let executor = components.task_executor().clone();
-            exexes.push(async move {
-                debug!(target: "reth::cli", id, "spawning exex");
-                let span = reth_tracing::tracing::info_span!("exex", id);
-
-                // init the exex
-                let exex = exex.launch(context).instrument(span.clone()).await.unwrap();
-
-                // spawn it as a crit task
-                executor.spawn_critical(
-                    "exex",
-                    async move {
-                        info!(target: "reth::cli", "ExEx started");
-                        match exex.await {
-                            Ok(_) => panic!("ExEx {id} finished. ExExes should run indefinitely"),
-                            Err(err) => panic!("ExEx {id} crashed: {err}"),
-                        }
-                    }
-                    .instrument(span),
-                );
-            });
+            exexes.push((id, exex, context));
         }
 
-        future::join_all(exexes).await;
-
-        // spawn exex manager
-        debug!(target: "reth::cli", "spawning exex manager");
-        let exex_manager = ExExManager::new(
+        // create the exex manager before launching the exexes, so that they can report their
+        // errors to it
+        let mut exex_manager = ExExManager::new(
             components.provider().clone(),
             exex_handles,
             DEFAULT_EXEX_MANAGER_CAPACITY,
             exex_wal,
             components.provider().finalized_block_stream(),
-        );
+        )
+        .with_finished_heights_storage(finished_heights_storage)?;
+        if config_container.config.exex.isolate_failures {
+            exex_manager = exex_manager.with_isolated_failures();
+        }
+        if config_container.config.exex.wal_replay {
+            exex_manager = exex_manager.with_wal_replay()?;
+        }
         let exex_manager_handle = exex_manager.handle();
-        components.task_executor().spawn_critical("exex manager", async move {
-            exex_manager.await.expect("exex manager crashed");
-        });
+
+        let executor = components.task_executor().clone();
+        for result in future::join_all(exexes.into_iter().map(|(id, exex, context)| {
+            launch_exex(id, exex, context, executor.clone(), exex_manager_handle.clone())
+        }))
+        .await
+        {
+            result.unwrap();
+        }
+
+        // spawn exex manager
+        debug!(target: "reth::cli", "spawning exex manager");
+        // spawn it as a crit task, holding the graceful shutdown guard until the buffered
+        // notifications are drained, so that the exexes don't have to process them again on restart
+        components.task_executor().spawn_critical_with_graceful_shutdown_signal(
+            "exex manager",
+            |shutdown| async move {
+                let mut exex_manager = exex_manager;
+                tokio::select! {
+                    result = &mut exex_manager => result.expect("exex manager crashed"),
+                    _guard = shutdown => {
+                        exex_manager
+                            .drain(DEFAULT_EXEX_MANAGER_DRAIN_TIMEOUT)
+                            .await
+                            .expect("exex manager crashed while draining");
+                    }
+                }
+            },
+        );
+
+        // serve the exex statuses over a unix domain socket, if configured
+        #[cfg(unix)]
+        if let Some(path) = config_container.config.exex.status_socket.clone() {
+            debug!(target: "reth::cli", path = %path.display(), "spawning exex status socket");
+            let status_socket = reth_exex::ExExStatusSocket::new(path, &exex_manager_handle);
+            components.task_executor().spawn(async move {
+                if let Err(err) = status_socket.serve().await {
+                    error!(target: "reth::cli", %err, "exex status socket failed");
+                }
+            });
+        }
 
         // send notifications from the blockchain tree to exex manager
         let mut canon_state_notifications = components.provider().subscribe_to_canonical_state();
@@ -136,7 +155,12 @@ impl<Node: FullNodeComponents + Clone> ExExLauncher<Node> {
 
         info!(target: "reth::cli", "ExEx Manager started");
 
-        Ok(Some(exex_manager_handle))
+        Ok(Some(ExExRegistry {
+            components,
+            config_container: Arc::new(config_container),
+            wal_handle,
+            manager_handle: exex_manager_handle,
+        }))
     }
 }
 
@@ -150,3 +174,223 @@ impl<Node: FullNodeComponents> Debug for ExExLauncher<Node> {
             .finish()
     }
 }
+
+/// Registers and unregisters execution extensions on the running node.
+///
+/// Only available if at least one `ExEx` was installed when the node was launched, because the
+/// `ExEx` manager is only running then. See [`FullNode::exex_registry`].
+pub struct ExExRegistry<Node: FullNodeComponents> {
+    components: Node,
+    config_container: Arc<WithConfigs<<Node::Types as NodeTypes>::ChainSpec>>,
+    wal_handle: WalHandle,
+    manager_handle: ExExManagerHandle,
+}
+
+impl<Node> ExExRegistry<Node>
+where
+    Node: FullNodeComponents + Clone,
+    <Node::Provider as DatabaseProviderFactory>::Provider:
+        HeaderProvider + StageCheckpointReader + BlockHashReader,
+{
+    /// Returns the handle to the `ExEx` manager.
+    pub const fn manager_handle(&self) -> &ExExManagerHandle {
+        &self.manager_handle
+    }
+
+    /// Launches the `ExEx` with the given ID, and registers it with the running `ExEx` manager.
+    ///
+    /// The `ExEx` is configured like the ones installed with
+    /// [`NodeBuilder::install_exex`](crate::NodeBuilder::install_exex), with the current head of
+    /// the node as [`ExExContext::head`]. See [`ExExManagerHandle::register_exex`] for the
+    /// notifications it receives.
+    ///
+    /// Returns an error if an `ExEx` with the same ID is already registered, or if the `ExEx`
+    /// failed to launch, in which case it's reported as errored.
+    pub async fn register_exex(
+        &self,
+        id: impl Into<String>,
+        exex: impl LaunchExEx<Node> + 'static,
+    ) -> eyre::Result<()> {
+        let id = id.into();
+        let head = self.config_container.config.lookup_head(self.components.provider())?;
+        let (handle, context) = new_exex(
+            id.clone(),
+            head,
+            &self.components,
+            &self.config_container,
+            self.wal_handle.clone(),
+        );
+        self.manager_handle.register_exex(handle).await?;
+
+        if let Err(err) = launch_exex(
+            id.clone(),
+            Box::new(exex),
+            context,
+            self.components.task_executor().clone(),
+            self.manager_handle.clone(),
+        )
+        .await
+        {
+            self.manager_handle.report_exex_error(id, format!("failed to launch: {err}"));
+            return Err(err)
+        }
+
+        Ok(())
+    }
+
+    /// Unregisters the `ExEx` with the given ID from the running `ExEx` manager, and returns its
+    /// final status. See [`ExExManagerHandle::unregister_exex`].
+    ///
+    /// The `ExEx` can be launched by the node at startup or registered with
+    /// [`Self::register_exex`]. It should stop when its [`ExExContext::stop_signal`] is
+    /// cancelled, which is not treated as a crash.
+    pub async fn unregister_exex(
+        &self,
+        id: impl Into<String>,
+        timeout: Duration,
+    ) -> Result<ExExStatus, ExExRegistrationError> {
+        self.manager_handle.unregister_exex(id, timeout).await
+    }
+}
+
+impl<Node: FullNodeComponents> Clone for ExExRegistry<Node> {
+    fn clone(&self) -> Self {
+        Self {
+            components: self.components.clone(),
+            config_container: self.config_container.clone(),
+            wal_handle: self.wal_handle.clone(),
+            manager_handle: self.manager_handle.clone(),
+        }
+    }
+}
+
+impl<Node: FullNodeComponents> Debug for ExExRegistry<Node> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ExExRegistry")
+            .field("components", &"...")
+            .field("config_container", &self.config_container)
+            .field("wal_handle", &self.wal_handle)
+            .field("manager_handle", &self.manager_handle)
+            .finish()
+    }
+}
+
+/// Creates the handle of the `ExEx` with the given ID, configured from the node config, and the
+/// context to launch it with.
+fn new_exex<Node: FullNodeComponents + Clone>(
+    id: String,
+    head: Head,
+    components: &Node,
+    config_container: &WithConfigs<<Node::Types as NodeTypes>::ChainSpec>,
+    wal_handle: WalHandle,
+) -> (ExExHandle, ExExContext<Node>) {
+    // create a new exex handle
+    let (mut handle, events, notifications) = ExExHandle::new(
+        id,
+        head,
+        components.provider().clone(),
+        components.block_executor().clone(),
+        wal_handle,
+    );
+    let exex_args = &config_container.config.exex;
+    if let Some(max_buffered_notifications) = exex_args.max_buffered_notifications {
+        handle = handle.with_max_buffered_notifications(
+            max_buffered_notifications,
+            overflow_policy(exex_args.overflow_policy),
+        );
+    }
+    if let Some(watchdog_timeout) = exex_args.watchdog_timeout {
+        handle = handle.with_watchdog(watchdog_timeout, watchdog_action(exex_args.watchdog_action));
+    }
+    if let Some(rate_limit_blocks) = exex_args.rate_limit_blocks {
+        handle = handle.with_rate_limit(rate_limit_blocks, exex_args.rate_limit_period);
+    }
+
+    // create the launch context for the exex
+    let context = ExExContext {
+        head,
+        config: config_container.config.clone(),
+        reth_config: config_container.toml_config.clone(),
+        components: components.clone(),
+        events,
+        replay_requests: handle.replay_requests(),
+        stop_signal: handle.stop_signal(),
+        notifications,
+    };
+
+    (handle, context)
+}
+
+/// Launches the `ExEx` with the given context, and spawns it as a critical task.
+async fn launch_exex<Node: FullNodeComponents>(
+    id: String,
+    exex: Box<dyn BoxedLaunchExEx<Node>>,
+    context: ExExContext<Node>,
+    executor: TaskExecutor,
+    exex_manager_handle: ExExManagerHandle,
+) -> eyre::Result<()> {
+    debug!(target: "reth::cli", id, "spawning exex");
+    let span = reth_tracing::tracing::info_span!("exex", id);
+    let stop_signal = context.stop_signal.clone();
+    let isolate_failures = context.config.exex.isolate_failures;
+
+    // init the exex
+    let exex = exex.launch(context).instrument(span.clone()).await?;
+
+    // spawn it as a crit task, holding the graceful shutdown guard until the exex
+    // finishes, so that the node waits for it to flush its state on shutdown
+    executor.spawn_critical_with_graceful_shutdown_signal("exex", |shutdown| {
+        async move {
+            info!(target: "reth::cli", "ExEx started");
+            let result = exex.await;
+            let Some(_guard) = shutdown.now_or_never() else {
+                match result {
+                    // the exex was unregistered or terminated by the manager
+                    Ok(_) if stop_signal.is_cancelled() => {
+                        info!(target: "reth::cli", "ExEx stopped");
+                        return
+                    }
+                    Ok(_) => {
+                        panic!("ExEx {id} finished. ExExes should run indefinitely")
+                    }
+                    // the manager removes the exex and reports it as errored, if configured to
+                    // isolate the failures of exexes
+                    Err(err) if isolate_failures => {
+                        error!(target: "reth::cli", %err, "ExEx crashed");
+                        exex_manager_handle.report_exex_error(id, err.to_string());
+                        return
+                    }
+                    Err(err) => panic!("ExEx {id} crashed: {err}"),
+                }
+            };
+            match result {
+                Ok(_) => info!(target: "reth::cli", "ExEx shut down"),
+                Err(err) => {
+                    error!(target: "reth::cli", %err, "ExEx crashed on shutdown")
+                }
+            }
+        }
+        .instrument(span)
+    });
+
+    Ok(())
+}
+
+/// Maps the overflow policy configured on the command line to the [`ExExOverflowPolicy`] of the
+/// manager.
+const fn overflow_policy(policy: ExExOverflowPolicyArg) -> ExExOverflowPolicy {
+    match policy {
+        ExExOverflowPolicyArg::Block => ExExOverflowPolicy::Block,
+        ExExOverflowPolicyArg::DropOldest => ExExOverflowPolicy::DropOldest,
+        ExExOverflowPolicyArg::Error => ExExOverflowPolicy::Error,
+    }
+}
+
+/// Maps the watchdog action configured on the command line to the [`ExExWatchdogAction`] of the
+/// manager.
+const fn watchdog_action(action: ExExWatchdogActionArg) -> ExExWatchdogAction {
+    match action {
+        ExExWatchdogActionArg::Warn => ExExWatchdogAction::Warn,
+        ExExWatchdogActionArg::Terminate => ExExWatchdogAction::Terminate,
+    }
+}
@@ -7,7 +7,7 @@ pub(crate) mod engine;
 
 pub use common::LaunchContext;
 use common::{Attached, LaunchContextWith, WithConfigs};
-pub use exex::ExExLauncher;
+pub use exex::{ExExLauncher, ExExRegistry};
 
 use std::{future::Future, sync::Arc};
 
@@ -177,7 +177,7 @@ where
             .with_components(components_builder, on_component_initialized).await?;
 
         // spawn exexs
-        let exex_manager_handle = ExExLauncher::new(
+        let exex_registry = ExExLauncher::new(
             ctx.head(),
             ctx.node_adapter().clone(),
             installed_exex,
@@ -185,6 +185,8 @@ where
         )
         .launch()
         .await?;
+        let exex_manager_handle =
+            exex_registry.as_ref().map(|registry| registry.manager_handle().clone());
 
         // create pipeline
         let network_client = ctx.components().network().fetch_client().await?;
@@ -365,6 +367,7 @@ where
             ctx.node_config(),
             jwt_secret,
             rpc,
+            exex_manager_handle.as_ref(),
         )
         .await?;
 
@@ -435,6 +438,7 @@ where
             rpc_registry,
             config: ctx.node_config().clone(),
             data_dir: ctx.data_dir().clone(),
+            exex_registry,
         };
         // Notify on node started
         on_node_started.on_event(full_node.clone())?;
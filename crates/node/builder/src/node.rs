@@ -17,7 +17,7 @@ use reth_tasks::TaskExecutor;
 use crate::{
     components::NodeComponentsBuilder,
     rpc::{RethRpcServerHandles, RpcRegistry},
-    NodeAdapter, NodeAddOns,
+    ExExRegistry, NodeAdapter, NodeAddOns,
 };
 
 /// A [`crate::Node`] is a [`NodeTypesWithEngine`] that comes with preconfigured components.
@@ -113,6 +113,9 @@ pub struct FullNode<Node: FullNodeComponents, AddOns: NodeAddOns<Node>> {
     pub config: NodeConfig<<Node::Types as NodeTypes>::ChainSpec>,
     /// The data dir of the node.
     pub data_dir: ChainPath<DataDirPath>,
+    /// Registers and unregisters execution extensions on the running node, if any were installed
+    /// when it was launched.
+    pub exex_registry: Option<ExExRegistry<Node>>,
 }
 
 impl<Node: FullNodeComponents, AddOns: NodeAddOns<Node>> Clone for FullNode<Node, AddOns> {
@@ -129,6 +132,7 @@ impl<Node: FullNodeComponents, AddOns: NodeAddOns<Node>> Clone for FullNode<Node
             rpc_registry: self.rpc_registry.clone(),
             config: self.config.clone(),
             data_dir: self.data_dir.clone(),
+            exex_registry: self.exex_registry.clone(),
         }
     }
 }
@@ -6,6 +6,7 @@ use std::{
 };
 
 use futures::TryFutureExt;
+use reth_exex::ExExManagerHandle;
 use reth_node_api::{BuilderProvider, FullNodeComponents, NodeTypes, NodeTypesWithEngine};
 use reth_node_core::{
     node_config::NodeConfig,
@@ -16,10 +17,13 @@ use reth_node_core::{
 };
 use reth_payload_builder::PayloadBuilderHandle;
 use reth_provider::providers::ProviderNodeTypes;
+use reth_rpc::ExExApi;
+use reth_rpc_api::ExExApiServer;
 use reth_rpc_builder::{
     auth::{AuthRpcModule, AuthServerHandle},
     config::RethRpcServerConfig,
-    RpcModuleBuilder, RpcRegistryInner, RpcServerHandle, TransportRpcModules,
+    RethRpcModule, RpcModuleBuilder, RpcModuleSelection, RpcRegistryInner, RpcServerHandle,
+    TransportRpcModules,
 };
 use reth_rpc_layer::JwtSecret;
 use reth_tasks::TaskExecutor;
@@ -299,6 +303,7 @@ pub async fn launch_rpc_servers<Node, Engine, EthApi>(
     config: &NodeConfig<<Node::Types as NodeTypes>::ChainSpec>,
     jwt_secret: JwtSecret,
     add_ons: RpcAddOns<Node, EthApi>,
+    exex_manager_handle: Option<&ExExManagerHandle>,
 ) -> eyre::Result<(RethRpcServerHandles, RpcRegistry<Node, EthApi>)>
 where
     Node: FullNodeComponents<Types: ProviderNodeTypes> + Clone,
@@ -319,6 +324,25 @@ where
         .with_block_executor(node.block_executor().clone())
         .build_with_auth_server(module_config, engine_api, EthApi::eth_api_builder());
 
+    // install the `ExEx` status API into the transports with the `reth` namespace configured
+    if let Some(exex_manager_handle) = exex_manager_handle {
+        let exex_api = ExExApi::new(exex_manager_handle.statuses()).into_rpc();
+        let module_config = modules.module_config().clone();
+        let has_reth_module = |selection: Option<&RpcModuleSelection>| {
+            selection
+                .is_some_and(|selection| selection.to_selection().contains(&RethRpcModule::Reth))
+        };
+        if has_reth_module(module_config.http()) {
+            modules.merge_http(exex_api.clone())?;
+        }
+        if has_reth_module(module_config.ws()) {
+            modules.merge_ws(exex_api.clone())?;
+        }
+        if has_reth_module(module_config.ipc()) {
+            modules.merge_ipc(exex_api)?;
+        }
+    }
+
     let mut registry = RpcRegistry { registry };
     let ctx = RpcContext {
         node: node.clone(),
@@ -0,0 +1,307 @@
+//! clap [Args](clap::Args) for `ExEx` configuration
+
+use std::{
+    fmt::{self, Display},
+    path::PathBuf,
+    time::Duration,
+};
+
+use clap::{builder::RangedU64ValueParser, Args, ValueEnum};
+use humantime::parse_duration;
+
+/// Parameters for configuring the execution extensions
+#[derive(Debug, Args, PartialEq, Eq, Clone)]
+#[command(next_help_heading = "ExEx")]
+pub struct ExExArgs {
+    /// Replay the notifications from the `ExEx` write-ahead log on startup.
+    ///
+    /// The notifications that were committed to the write-ahead log, but not processed by the
+    /// execution extensions before the node stopped, e.g. because it crashed, are delivered
+    /// again.
+    #[arg(long = "exex.wal-replay", help_heading = "ExEx")]
+    pub wal_replay: bool,
+
+    /// Publish the progress of the execution extensions over a Unix domain socket at the given
+    /// path.
+    ///
+    /// Every connection to the socket receives the statuses as newline-delimited JSON. Only
+    /// supported on Unix.
+    #[arg(long = "exex.status-socket", value_name = "PATH", help_heading = "ExEx")]
+    pub status_socket: Option<PathBuf>,
+
+    /// The max number of buffered notifications not yet delivered to each execution extension.
+    ///
+    /// Unlimited if not set, in which case only the capacity of the `ExEx` manager applies.
+    #[arg(
+        long = "exex.max-buffered-notifications",
+        value_name = "COUNT",
+        value_parser = RangedU64ValueParser::<usize>::new().range(1..),
+        help_heading = "ExEx"
+    )]
+    pub max_buffered_notifications: Option<usize>,
+
+    /// The policy applied when an execution extension has more buffered notifications than set
+    /// with `--exex.max-buffered-notifications`.
+    #[arg(
+        long = "exex.overflow-policy",
+        value_name = "POLICY",
+        requires = "max_buffered_notifications",
+        default_value_t = ExExOverflowPolicy::Block,
+        help_heading = "ExEx"
+    )]
+    pub overflow_policy: ExExOverflowPolicy,
+
+    /// Supervise the progress of each execution extension, taking the action set with
+    /// `--exex.watchdog-action` if it has notifications to process, but doesn't finish any of
+    /// them for the given duration.
+    ///
+    /// Parses strings using [`humantime::parse_duration`], e.g. `30s` or `5m`.
+    #[arg(
+        long = "exex.watchdog-timeout",
+        value_name = "DURATION",
+        value_parser = parse_non_zero_duration,
+        help_heading = "ExEx"
+    )]
+    pub watchdog_timeout: Option<Duration>,
+
+    /// The action taken when an execution extension made no progress for the duration set with
+    /// `--exex.watchdog-timeout`.
+    #[arg(
+        long = "exex.watchdog-action",
+        value_name = "ACTION",
+        requires = "watchdog_timeout",
+        default_value_t = ExExWatchdogAction::Warn,
+        help_heading = "ExEx"
+    )]
+    pub watchdog_action: ExExWatchdogAction,
+
+    /// Limit the notifications delivered to each execution extension to at most the given
+    /// number of committed blocks per `--exex.rate-limit-period`.
+    ///
+    /// The notifications that are not delivered yet stay buffered in the `ExEx` manager.
+    #[arg(
+        long = "exex.rate-limit-blocks",
+        value_name = "COUNT",
+        value_parser = RangedU64ValueParser::<u64>::new().range(1..),
+        help_heading = "ExEx"
+    )]
+    pub rate_limit_blocks: Option<u64>,
+
+    /// The period of the limit set with `--exex.rate-limit-blocks`.
+    ///
+    /// Parses strings using [`humantime::parse_duration`], e.g. `500ms` or `1s`.
+    #[arg(
+        long = "exex.rate-limit-period",
+        value_name = "DURATION",
+        value_parser = parse_non_zero_duration,
+        requires = "rate_limit_blocks",
+        default_value = "1s",
+        help_heading = "ExEx"
+    )]
+    pub rate_limit_period: Duration,
+
+    /// Keep the node running when an execution extension fails, i.e. returns an error or stops
+    /// receiving notifications.
+    ///
+    /// The failed execution extension is removed from the node and reported with the `errored`
+    /// status. By default, the failure of an execution extension shuts the node down.
+    #[arg(long = "exex.isolate-failures", help_heading = "ExEx")]
+    pub isolate_failures: bool,
+}
+
+impl Default for ExExArgs {
+    fn default() -> Self {
+        Self {
+            wal_replay: false,
+            status_socket: None,
+            max_buffered_notifications: None,
+            overflow_policy: ExExOverflowPolicy::default(),
+            watchdog_timeout: None,
+            watchdog_action: ExExWatchdogAction::default(),
+            rate_limit_blocks: None,
+            rate_limit_period: Duration::from_secs(1),
+            isolate_failures: false,
+        }
+    }
+}
+
+/// The policy applied when an execution extension has too many buffered notifications.
+#[derive(Debug, Copy, Clone, Default, ValueEnum, Eq, PartialEq)]
+pub enum ExExOverflowPolicy {
+    /// Stop accepting new notifications until the execution extension catches up
+    #[default]
+    Block,
+    /// Skip the oldest notifications not yet delivered to the execution extension
+    DropOldest,
+    /// Terminate the execution extension
+    Error,
+}
+
+impl Display for ExExOverflowPolicy {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Block => write!(f, "block"),
+            Self::DropOldest => write!(f, "drop-oldest"),
+            Self::Error => write!(f, "error"),
+        }
+    }
+}
+
+/// The action taken when an execution extension made no progress.
+#[derive(Debug, Copy, Clone, Default, ValueEnum, Eq, PartialEq)]
+pub enum ExExWatchdogAction {
+    /// Log a warning
+    #[default]
+    Warn,
+    /// Terminate the execution extension
+    Terminate,
+}
+
+impl Display for ExExWatchdogAction {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Warn => write!(f, "warn"),
+            Self::Terminate => write!(f, "terminate"),
+        }
+    }
+}
+
+/// Parses a non-zero duration using [`humantime::parse_duration`].
+fn parse_non_zero_duration(arg: &str) -> eyre::Result<Duration> {
+    let duration = parse_duration(arg)?;
+    eyre::ensure!(!duration.is_zero(), "duration must be non-zero");
+    Ok(duration)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use clap::Parser;
+
+    /// A helper type to parse Args more easily
+    #[derive(Parser)]
+    struct CommandParser<T: Args> {
+        #[command(flatten)]
+        args: T,
+    }
+
+    #[test]
+    fn test_parse_exex_args() {
+        let args = CommandParser::<ExExArgs>::parse_from(["reth"]).args;
+        assert_eq!(args, ExExArgs::default());
+
+        let args = CommandParser::<ExExArgs>::parse_from([
+            "reth",
+            "--exex.wal-replay",
+            "--exex.status-socket",
+            "/tmp/exex.sock",
+            "--exex.isolate-failures",
+        ])
+        .args;
+        assert_eq!(
+            args,
+            ExExArgs {
+                wal_replay: true,
+                status_socket: Some(PathBuf::from("/tmp/exex.sock")),
+                isolate_failures: true,
+                ..Default::default()
+            }
+        );
+    }
+
+    #[test]
+    fn test_parse_exex_max_buffered_notifications() {
+        let args = CommandParser::<ExExArgs>::parse_from([
+            "reth",
+            "--exex.max-buffered-notifications",
+            "100",
+            "--exex.overflow-policy",
+            "drop-oldest",
+        ])
+        .args;
+        assert_eq!(args.max_buffered_notifications, Some(100));
+        assert_eq!(args.overflow_policy, ExExOverflowPolicy::DropOldest);
+
+        // the policy only applies with a limit
+        assert!(CommandParser::<ExExArgs>::try_parse_from([
+            "reth",
+            "--exex.overflow-policy",
+            "error"
+        ])
+        .is_err());
+        assert!(CommandParser::<ExExArgs>::try_parse_from([
+            "reth",
+            "--exex.max-buffered-notifications",
+            "0"
+        ])
+        .is_err());
+    }
+
+    #[test]
+    fn test_parse_exex_watchdog() {
+        let args = CommandParser::<ExExArgs>::parse_from([
+            "reth",
+            "--exex.watchdog-timeout",
+            "30s",
+            "--exex.watchdog-action",
+            "terminate",
+        ])
+        .args;
+        assert_eq!(args.watchdog_timeout, Some(Duration::from_secs(30)));
+        assert_eq!(args.watchdog_action, ExExWatchdogAction::Terminate);
+
+        // the action only applies with a timeout
+        assert!(CommandParser::<ExExArgs>::try_parse_from([
+            "reth",
+            "--exex.watchdog-action",
+            "warn"
+        ])
+        .is_err());
+        assert!(CommandParser::<ExExArgs>::try_parse_from([
+            "reth",
+            "--exex.watchdog-timeout",
+            "0s"
+        ])
+        .is_err());
+    }
+
+    #[test]
+    fn test_parse_exex_rate_limit() {
+        let args =
+            CommandParser::<ExExArgs>::parse_from(["reth", "--exex.rate-limit-blocks", "10"]).args;
+        assert_eq!(args.rate_limit_blocks, Some(10));
+        assert_eq!(args.rate_limit_period, Duration::from_secs(1));
+
+        let args = CommandParser::<ExExArgs>::parse_from([
+            "reth",
+            "--exex.rate-limit-blocks",
+            "10",
+            "--exex.rate-limit-period",
+            "500ms",
+        ])
+        .args;
+        assert_eq!(args.rate_limit_period, Duration::from_millis(500));
+
+        // the period only applies with a limit
+        assert!(CommandParser::<ExExArgs>::try_parse_from([
+            "reth",
+            "--exex.rate-limit-period",
+            "1s"
+        ])
+        .is_err());
+        assert!(CommandParser::<ExExArgs>::try_parse_from([
+            "reth",
+            "--exex.rate-limit-blocks",
+            "0"
+        ])
+        .is_err());
+        assert!(CommandParser::<ExExArgs>::try_parse_from([
+            "reth",
+            "--exex.rate-limit-blocks",
+            "10",
+            "--exex.rate-limit-period",
+            "0s"
+        ])
+        .is_err());
+    }
+}
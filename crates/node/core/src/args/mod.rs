@@ -48,6 +48,10 @@ pub use dev::DevArgs;
 mod pruning;
 pub use pruning::PruningArgs;
 
+/// ExExArgs for configuring the execution extensions
+mod exex;
+pub use exex::{ExExArgs, ExExOverflowPolicy, ExExWatchdogAction};
+
 /// DatadirArgs for configuring data storage paths
 mod datadir_args;
 pub use datadir_args::DatadirArgs;
@@ -355,6 +355,11 @@ impl<D> ChainPath<D> {
     pub fn exex_wal(&self) -> PathBuf {
         self.data_dir().join("exex/wal")
     }
+
+    /// Returns the path to the file with the finished heights of ExExes for this chain.
+    pub fn exex_finished_heights(&self) -> PathBuf {
+        self.data_dir().join("exex/finished_heights")
+    }
 }
 
 impl<D> AsRef<Path> for ChainPath<D> {
@@ -2,7 +2,7 @@
 
 use crate::{
     args::{
-        DatabaseArgs, DatadirArgs, DebugArgs, DevArgs, NetworkArgs, PayloadBuilderArgs,
+        DatabaseArgs, DatadirArgs, DebugArgs, DevArgs, ExExArgs, NetworkArgs, PayloadBuilderArgs,
         PruningArgs, RpcServerArgs, TxPoolArgs,
     },
     dirs::{ChainPath, DataDirPath},
@@ -127,6 +127,9 @@ pub struct NodeConfig<ChainSpec> {
 
     /// All pruning related arguments
     pub pruning: PruningArgs,
+
+    /// All `ExEx` related arguments with --exex prefix
+    pub exex: ExExArgs,
 }
 
 impl NodeConfig<ChainSpec> {
@@ -154,6 +157,7 @@ impl<ChainSpec> NodeConfig<ChainSpec> {
             db: DatabaseArgs::default(),
             dev: DevArgs::default(),
             pruning: PruningArgs::default(),
+            exex: ExExArgs::default(),
             datadir: DatadirArgs::default(),
         }
     }
@@ -255,6 +259,12 @@ impl<ChainSpec> NodeConfig<ChainSpec> {
         self
     }
 
+    /// Set the `ExEx` args for the node
+    pub fn with_exex(mut self, exex: ExExArgs) -> Self {
+        self.exex = exex;
+        self
+    }
+
     /// Returns pruning configuration.
     pub fn prune_config(&self) -> Option<PruneConfig>
     where
@@ -445,6 +455,7 @@ impl<ChainSpec> Clone for NodeConfig<ChainSpec> {
             db: self.db,
             dev: self.dev,
             pruning: self.pruning.clone(),
+            exex: self.exex.clone(),
             datadir: self.datadir.clone(),
         }
     }
@@ -0,0 +1,115 @@
+#![allow(missing_docs)]
+
+use alloy_consensus::{Header, SignableTransaction, Transaction, TxEnvelope, TxLegacy};
+use alloy_primitives::B256;
+use criterion::{criterion_group, criterion_main, BenchmarkId, Criterion};
+use pprof::criterion::{Output, PProfProfiler};
+use rayon::prelude::*;
+use reth_primitives::{sign_message, traits::BlockBody, Requests};
+
+/// Numbers of transactions in the benchmarked blocks, from blocks below the parallel recovery
+/// threshold to a full mainnet block of simple transfers.
+const NUM_TRANSACTIONS: [usize; 7] = [2, 5, 10, 20, 50, 100, 1500];
+
+/// Minimal [`BlockBody`] implementation over signed [`TxEnvelope`]s.
+#[derive(
+    Debug,
+    Clone,
+    PartialEq,
+    Eq,
+    Default,
+    serde::Serialize,
+    serde::Deserialize,
+    alloy_rlp::RlpEncodable,
+    alloy_rlp::RlpDecodable,
+)]
+struct BenchBody {
+    transactions: Vec<TxEnvelope>,
+}
+
+impl BlockBody for BenchBody {
+    type SignedTransaction = TxEnvelope;
+    type Header = Header;
+
+    fn transactions(&self) -> &[Self::SignedTransaction] {
+        &self.transactions
+    }
+
+    fn ommers(&self) -> &[Self::Header] {
+        &[]
+    }
+
+    fn requests(&self) -> Option<&Requests> {
+        None
+    }
+
+    fn calculate_tx_root(&self) -> B256 {
+        unreachable!("not benchmarked")
+    }
+
+    fn calculate_ommers_root(&self) -> B256 {
+        unreachable!("not benchmarked")
+    }
+
+    fn blob_versioned_hashes_iter(&self) -> impl Iterator<Item = &B256> + '_ {
+        self.transactions.iter().filter_map(|tx| tx.blob_versioned_hashes()).flatten()
+    }
+
+    fn size(&self) -> usize {
+        self.transactions.capacity() * core::mem::size_of::<TxEnvelope>()
+    }
+}
+
+/// Returns a block body with the given number of legacy transactions, each signed by a random key.
+fn body(num_transactions: usize) -> BenchBody {
+    let transactions = (0..num_transactions as u64)
+        .map(|nonce| {
+            let tx = TxLegacy { nonce, ..Default::default() };
+            let signature = sign_message(B256::random(), tx.signature_hash()).unwrap();
+            tx.into_signed(signature).into()
+        })
+        .collect();
+    BenchBody { transactions }
+}
+
+/// Benchmarks the recovery of the senders of all transactions in a block sequentially, in parallel,
+/// and with [`BlockBody::recover_signers_par`], which only recovers in parallel above the
+/// threshold.
+pub fn criterion_benchmark(c: &mut Criterion) {
+    let mut group = c.benchmark_group("Recover Signers");
+
+    for num_transactions in NUM_TRANSACTIONS {
+        let body = body(num_transactions);
+
+        group.bench_with_input(
+            BenchmarkId::new("sequential", num_transactions),
+            &body,
+            |b, body| b.iter(|| body.recover_signers().unwrap()),
+        );
+
+        group.bench_with_input(BenchmarkId::new("parallel", num_transactions), &body, |b, body| {
+            b.iter(|| {
+                body.transactions()
+                    .par_iter()
+                    .map(|tx| BenchBody::recover_transaction_signer(tx).ok())
+                    .collect::<Option<Vec<_>>>()
+                    .unwrap()
+            })
+        });
+
+        group.bench_with_input(
+            BenchmarkId::new("recover_signers_par", num_transactions),
+            &body,
+            |b, body| b.iter(|| body.recover_signers_par().unwrap()),
+        );
+    }
+
+    group.finish();
+}
+
+criterion_group! {
+    name = benches;
+    config = Criterion::default().with_profiler(PProfProfiler::new(100, Output::Flamegraph(None)));
+    targets = criterion_benchmark
+}
+criterion_main!(benches);
@@ -30,6 +30,7 @@ pub mod constants;
 pub mod proofs;
 mod receipt;
 pub use reth_static_file_types as static_file;
+pub mod traits;
 pub mod transaction;
 #[cfg(any(test, feature = "arbitrary"))]
 pub use block::{generate_valid_header, valid_header_strategy};
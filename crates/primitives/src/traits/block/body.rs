@@ -1,12 +1,27 @@
 //! Block body abstraction.
 
-use alloc::fmt;
+use alloc::{
+    collections::{BTreeMap, BTreeSet},
+    fmt,
+};
 use core::ops;
 
-use alloy_consensus::{BlockHeader, Transaction, TxType};
-use alloy_primitives::{Address, B256};
-
-use crate::{proofs, traits::Block, Requests, Withdrawals};
+use alloy_consensus::{BlockHeader, SignableTransaction, Signed, Transaction, TxEnvelope, TxType};
+use alloy_eips::{
+    eip2718::Encodable2718, eip4844::DATA_GAS_PER_BLOB, eip7702::SignedAuthorization,
+};
+use alloy_primitives::{Address, Signature, TxNumber, B256};
+#[cfg(feature = "rayon")]
+use rayon::prelude::*;
+
+#[cfg(feature = "rayon")]
+use crate::transaction::PARALLEL_SENDER_RECOVERY_THRESHOLD;
+use crate::{
+    proofs,
+    traits::{Block, MaybeSerde},
+    transaction::recover_signer,
+    GotExpected, GotExpectedBoxed, Requests, Withdrawals,
+};
 
 /// Abstraction for block's body.
 pub trait BlockBody:
@@ -15,8 +30,7 @@ pub trait BlockBody:
     + PartialEq
     + Eq
     + Default
-    + serde::Serialize
-    + for<'de> serde::Deserialize<'de>
+    + MaybeSerde
     + alloy_rlp::Encodable
     + alloy_rlp::Decodable
 {
@@ -27,16 +41,105 @@ pub trait BlockBody:
     /// Header type (uncle blocks).
     type Header: BlockHeader;
 
+    /// The EIP-2718 type of system transactions of the chain, if any, e.g. `0x7E` for deposit
+    /// transactions on OP Stack chains.
+    const SYSTEM_TRANSACTION_TYPE: Option<u8> = None;
+
     /// Returns reference to transactions in block.
     fn transactions(&self) -> &[Self::SignedTransaction];
 
-    /// Returns [`Withdrawals`] in the block, if any.
-    // todo: branch out into extension trait
-    fn withdrawals(&self) -> Option<&Withdrawals>;
+    /// Returns the number of transactions in the block body.
+    fn transaction_count(&self) -> usize {
+        self.transactions().len()
+    }
+
+    /// Returns whether or not the block body contains any transactions.
+    fn is_empty(&self) -> bool {
+        self.transactions().is_empty()
+    }
+
+    /// Returns the first transaction in the block body, if any.
+    fn first_transaction(&self) -> Option<&Self::SignedTransaction> {
+        self.transactions().first()
+    }
+
+    /// Returns the last transaction in the block body, if any.
+    fn last_transaction(&self) -> Option<&Self::SignedTransaction> {
+        self.transactions().last()
+    }
+
+    /// Returns an iterator over the transactions in the block body paired with their transaction
+    /// numbers, given the number of the first transaction in the block, e.g. from the stored block
+    /// body indices.
+    ///
+    /// The transaction numbers are the keys of the receipts and senders tables, so this aligns the
+    /// transactions with their execution results.
+    fn transactions_with_tx_numbers(
+        &self,
+        first_tx_num: TxNumber,
+    ) -> impl Iterator<Item = (TxNumber, &Self::SignedTransaction)> + '_ {
+        (first_tx_num..).zip(self.transactions())
+    }
+
+    /// Returns the transaction with the given hash, if it's in the block body.
+    ///
+    /// Note: the hashes are computed from the EIP-2718 encoding of the transactions,
+    /// short-circuiting on the first match.
+    fn transaction_by_hash(&self, hash: &B256) -> Option<&Self::SignedTransaction>
+    where
+        Self::SignedTransaction: Encodable2718,
+    {
+        self.transactions().iter().find(|tx| tx.trie_hash() == *hash)
+    }
+
+    /// Returns whether or not the transaction with the given hash is in the block body.
+    ///
+    /// See [`Self::transaction_by_hash`].
+    fn contains_transaction(&self, hash: &B256) -> bool
+    where
+        Self::SignedTransaction: Encodable2718,
+    {
+        self.transaction_by_hash(hash).is_some()
+    }
+
+    /// Returns the transactions removed and added by replacing this block body with the other one,
+    /// e.g. the body of the new block at the same height after a reorg.
+    ///
+    /// The transactions are compared by their hashes, computed from their EIP-2718 encoding. The
+    /// order of the transactions within each block body is preserved.
+    fn diff<'a>(&'a self, other: &'a Self) -> BodyDiff<'a, Self::SignedTransaction>
+    where
+        Self::SignedTransaction: Encodable2718,
+    {
+        let hashed = |body: &'a Self| -> Vec<(B256, &'a Self::SignedTransaction)> {
+            body.transactions().iter().map(|tx| (tx.trie_hash(), tx)).collect()
+        };
+        let (old, new) = (hashed(self), hashed(other));
+        let old_hashes = old.iter().map(|(hash, _)| *hash).collect::<BTreeSet<_>>();
+        let new_hashes = new.iter().map(|(hash, _)| *hash).collect::<BTreeSet<_>>();
+
+        BodyDiff {
+            removed: old
+                .into_iter()
+                .filter(|(hash, _)| !new_hashes.contains(hash))
+                .map(|(_, tx)| tx)
+                .collect(),
+            added: new
+                .into_iter()
+                .filter(|(hash, _)| !old_hashes.contains(hash))
+                .map(|(_, tx)| tx)
+                .collect(),
+        }
+    }
 
     /// Returns reference to uncle block headers.
     fn ommers(&self) -> &[Self::Header];
 
+    /// Returns the number of uncle block headers in the block body.
+    fn ommers_count(&self) -> usize {
+        self.ommers().len()
+    }
+
     /// Returns [`Request`] in block, if any.
     fn requests(&self) -> Option<&Requests>;
 
@@ -51,10 +154,16 @@ pub trait BlockBody:
     /// Calculate the ommers root for the block body.
     fn calculate_ommers_root(&self) -> B256;
 
-    /// Calculate the withdrawals root for the block body, if withdrawals exist. If there are no
-    /// withdrawals, this will return `None`.
-    fn calculate_withdrawals_root(&self) -> Option<B256> {
-        Some(proofs::calculate_withdrawals_root(self.withdrawals()?))
+    /// Returns an error if the transaction root of the block body doesn't match the expected one,
+    /// e.g. the transactions root of the block header.
+    fn validate_tx_root(&self, expected: B256) -> Result<(), BlockBodyError> {
+        let got = self.calculate_tx_root();
+        if got != expected {
+            return Err(BlockBodyError::TransactionRootMismatch(
+                GotExpected { got, expected }.into(),
+            ))
+        }
+        Ok(())
     }
 
     /// Calculate the requests root for the block body, if requests exist. If there are no
@@ -64,21 +173,150 @@ pub trait BlockBody:
     }
 
     /// Recover signer addresses for all transactions in the block body.
-    fn recover_signers(&self) -> Option<Vec<Address>>;
+    ///
+    /// Returns `None` if the signer of any transaction couldn't be recovered. See
+    /// [`Self::try_recover_signers`] for the reason of the failure.
+    fn recover_signers(&self) -> Option<Vec<Address>>
+    where
+        Self::SignedTransaction: SignerRecoverable,
+    {
+        self.try_recover_signers().ok()
+    }
+
+    /// Recover signer addresses for all transactions in the block body.
+    ///
+    /// Returns a [`RecoveryError`] with the index of the first transaction whose signer couldn't
+    /// be recovered.
+    fn try_recover_signers(&self) -> Result<Vec<Address>, RecoveryError>
+    where
+        Self::SignedTransaction: SignerRecoverable,
+    {
+        self.transactions()
+            .iter()
+            .enumerate()
+            .map(|(index, tx)| {
+                Self::recover_transaction_signer(tx)
+                    .map_err(|reason| RecoveryError { index, reason })
+            })
+            .collect()
+    }
+
+    /// Recover signer addresses for all transactions in the block body in parallel, preserving
+    /// the order of the transactions.
+    ///
+    /// Falls back to sequential recovery if the block body has fewer transactions than the
+    /// threshold at which parallel recovery pays off, which depends on the number of threads
+    /// available: 10 transactions with up to 8 threads, and 5 with more. With a single thread,
+    /// recovery is always sequential.
+    #[cfg(feature = "rayon")]
+    fn recover_signers_par(&self) -> Option<Vec<Address>>
+    where
+        Self::SignedTransaction: SignerRecoverable + Sync,
+    {
+        let transactions = self.transactions();
+        if transactions.len() < *PARALLEL_SENDER_RECOVERY_THRESHOLD {
+            return self.recover_signers()
+        }
+
+        transactions.par_iter().map(|tx| Self::recover_transaction_signer(tx).ok()).collect()
+    }
+
+    /// Returns all transactions in the block body paired with their recovered signers.
+    ///
+    /// Returns `None` if the signer of any transaction couldn't be recovered.
+    fn transactions_with_senders(&self) -> Option<Vec<(&Self::SignedTransaction, Address)>>
+    where
+        Self::SignedTransaction: SignerRecoverable,
+    {
+        let signers = self.recover_signers()?;
+        Some(self.transactions().iter().zip(signers).collect())
+    }
+
+    /// Returns all transactions in the block body sent by the given address.
+    ///
+    /// Returns `None` if the signer of any transaction couldn't be recovered.
+    ///
+    /// Note: this recovers the signers of all transactions on every call. Callers querying
+    /// multiple senders should recover the signers once with [`Self::recover_signers`] and reuse
+    /// them instead.
+    fn transactions_from(&self, sender: Address) -> Option<Vec<&Self::SignedTransaction>>
+    where
+        Self::SignedTransaction: SignerRecoverable,
+    {
+        Some(
+            self.transactions_with_senders()?
+                .into_iter()
+                .filter_map(|(tx, signer)| (signer == sender).then_some(tx))
+                .collect(),
+        )
+    }
+
+    /// Recover signer address of a single transaction of the block body.
+    ///
+    /// This ensures that the signature has a low `s` value, as specified in
+    /// [EIP-2](https://eips.ethereum.org/EIPS/eip-2).
+    fn recover_transaction_signer(
+        tx: &Self::SignedTransaction,
+    ) -> Result<Address, RecoveryErrorReason>
+    where
+        Self::SignedTransaction: SignerRecoverable,
+    {
+        let signature = tx.signature();
+        recover_signer(signature, tx.signature_hash()).ok_or_else(|| {
+            if signature.normalize_s().is_some() {
+                RecoveryErrorReason::InvalidSignature
+            } else {
+                RecoveryErrorReason::PublicKeyRecovery
+            }
+        })
+    }
+
+    /// Returns whether or not any transaction in the block body matches the given predicate,
+    /// short-circuiting on the first match.
+    fn any_transaction(&self, f: impl Fn(&Self::SignedTransaction) -> bool) -> bool {
+        self.transactions().iter().any(f)
+    }
 
     /// Returns whether or not the block body contains any blob transactions.
+    ///
+    /// Note: blob transactions in a block body only reference their blobs by the versioned hashes,
+    /// see [`Self::blob_versioned_hashes_iter`]. The blob sidecars are never part of the block
+    /// body, and have to be looked up separately, e.g. in the blob store of the transaction pool,
+    /// to tell whether the blob data is locally available.
     fn has_blob_transactions(&self) -> bool {
-        self.transactions().iter().any(|tx| tx.ty() as u8 == TxType::Eip4844 as u8)
+        self.any_transaction(|tx| tx.ty() == TxType::Eip4844 as u8)
     }
 
     /// Returns whether or not the block body contains any EIP-7702 transactions.
     fn has_eip7702_transactions(&self) -> bool {
-        self.transactions().iter().any(|tx| tx.ty() as u8 == TxType::Eip7702 as u8)
+        self.any_transaction(|tx| tx.ty() == TxType::Eip7702 as u8)
+    }
+
+    /// Returns the number of transactions of each type in the block body, keyed by the EIP-2718
+    /// transaction type.
+    ///
+    /// The types are not converted into [`TxType`], so that the transaction types unknown to it,
+    /// e.g. deposit transactions, are counted too.
+    fn tx_count_by_type(&self) -> BTreeMap<u8, usize> {
+        let mut counts = BTreeMap::new();
+        for tx in self.transactions() {
+            *counts.entry(tx.ty()).or_default() += 1;
+        }
+        counts
+    }
+
+    /// Partitions the transactions in the block body into legacy transactions and typed
+    /// transactions, i.e. transactions of any type introduced by
+    /// [EIP-2718](https://eips.ethereum.org/EIPS/eip-2718), in that order.
+    fn partition_by_legacy(
+        &self,
+    ) -> (Vec<&Self::SignedTransaction>, Vec<&Self::SignedTransaction>) {
+        self.transactions().iter().partition(|tx| tx.ty() == TxType::Legacy as u8)
     }
 
     /// Returns an iterator over all blob transactions of the block
     fn blob_transactions_iter(&self) -> impl Iterator<Item = &Self::SignedTransaction> + '_ {
-        self.transactions().iter().filter(|tx| tx.ty() as u8 == TxType::Eip4844 as u8)
+        self.transactions().iter().filter(|tx| tx.ty() == TxType::Eip4844 as u8)
     }
 
     /// Returns only the blob transactions, if any, from the block body.
@@ -86,6 +324,33 @@ pub trait BlockBody:
         self.blob_transactions_iter().collect()
     }
 
+    /// Returns an iterator over all system transactions of the block, i.e. transactions of
+    /// [`Self::SYSTEM_TRANSACTION_TYPE`].
+    fn system_transactions_iter(&self) -> impl Iterator<Item = &Self::SignedTransaction> + '_ {
+        self.transactions().iter().filter(|tx| Self::SYSTEM_TRANSACTION_TYPE == Some(tx.ty()))
+    }
+
+    /// Returns an iterator over all EIP-7702 transactions of the block
+    fn eip7702_transactions_iter(&self) -> impl Iterator<Item = &Self::SignedTransaction> + '_ {
+        self.transactions().iter().filter(|tx| tx.ty() == TxType::Eip7702 as u8)
+    }
+
+    /// Returns only the EIP-7702 transactions, if any, from the block body.
+    fn eip7702_transactions(&self) -> Vec<&Self::SignedTransaction> {
+        self.eip7702_transactions_iter().collect()
+    }
+
+    /// Returns an iterator over the authorizations of all EIP-7702 transactions of the block body,
+    /// in order.
+    fn authorization_lists_iter(&self) -> impl Iterator<Item = &SignedAuthorization> + '_ {
+        self.transactions().iter().filter_map(|tx| tx.authorization_list()).flatten()
+    }
+
+    /// Returns the authorizations of all EIP-7702 transactions of the block body, in order.
+    fn authorization_lists(&self) -> Vec<&SignedAuthorization> {
+        self.authorization_lists_iter().collect()
+    }
+
     /// Returns an iterator over all blob versioned hashes from the block body.
     fn blob_versioned_hashes_iter(&self) -> impl Iterator<Item = &B256> + '_;
 
@@ -94,8 +359,129 @@ pub trait BlockBody:
         self.blob_versioned_hashes_iter().collect()
     }
 
+    /// Returns the total number of blobs of all blob transactions in the block body.
+    fn blob_count(&self) -> usize {
+        self.blob_versioned_hashes_iter().count()
+    }
+
+    /// Returns an error if the block body has more blobs than the given maximum, e.g.
+    /// [`MAX_BLOBS_PER_BLOCK`](crate::constants::eip4844::MAX_BLOBS_PER_BLOCK).
+    fn validate_blob_count(&self, max: usize) -> Result<(), BlockBodyError> {
+        let count = self.blob_count();
+        if count > max {
+            return Err(BlockBodyError::TooManyBlobs { count, max })
+        }
+        Ok(())
+    }
+
+    /// Returns the total blob gas used by all blob transactions in the block body.
+    fn blob_gas_used(&self) -> u64 {
+        self.blob_count() as u64 * DATA_GAS_PER_BLOB
+    }
+
+    /// Returns the sum of gas limits of all transactions in the block body.
+    fn total_gas_limit(&self) -> u64 {
+        self.transactions().iter().map(|tx| tx.gas_limit()).sum()
+    }
+
+    /// Returns the effective gas price of each transaction in the block body, in order, given the
+    /// base fee of the block.
+    ///
+    /// For EIP-1559 style transactions, this is `min(max_fee_per_gas, base_fee +
+    /// max_priority_fee_per_gas)`. For legacy transactions, this is the gas price.
+    fn effective_gas_prices(&self, base_fee: u64) -> Vec<u128> {
+        self.transactions()
+            .iter()
+            .map(|tx| {
+                tx.effective_tip_per_gas(base_fee)
+                    .map_or_else(|| tx.max_fee_per_gas(), |tip| tip + base_fee as u128)
+            })
+            .collect()
+    }
+
+    /// Estimates the priority fees paid to the fee recipient of the block with the given header,
+    /// in wei.
+    ///
+    /// The gas used by each transaction isn't known without its receipt, so the total gas used of
+    /// the header is attributed to the transactions proportionally to their gas limits. The
+    /// effective tip of each transaction is computed with the base fee of the header.
+    ///
+    /// Note: this excludes direct transfers to the fee recipient, e.g. by a contract call, which
+    /// can only be known by executing the block.
+    fn estimated_coinbase_tips(&self, header: &Self::Header) -> u128 {
+        let base_fee = header.base_fee_per_gas().unwrap_or_default();
+        let gas_used = header.gas_used() as u128;
+        let total_gas_limit = self.total_gas_limit() as u128;
+        self.transactions()
+            .iter()
+            .map(|tx| {
+                let gas_limit = tx.gas_limit() as u128;
+                let gas = if gas_used < total_gas_limit {
+                    gas_limit * gas_used / total_gas_limit
+                } else {
+                    gas_limit
+                };
+                tx.effective_tip_per_gas(base_fee).unwrap_or_default().saturating_mul(gas)
+            })
+            .fold(0, u128::saturating_add)
+    }
+
+    /// Returns the total size of the calldata of all transactions in the block body, in bytes.
+    ///
+    /// Blob sidecars are not part of the transaction input, and thus are not included.
+    fn total_input_bytes(&self) -> usize {
+        self.transactions().iter().map(|tx| tx.input().len()).sum()
+    }
+
+    /// Returns the distinct recipient addresses of all transactions in the block body.
+    ///
+    /// Contract creation transactions have no recipient, and thus don't contribute any address.
+    fn to_addresses(&self) -> BTreeSet<Address> {
+        self.transactions().iter().filter_map(|tx| tx.to().to().copied()).collect()
+    }
+
+    /// Returns the total number of access list entries, i.e. accessed addresses, of all
+    /// transactions in the block body.
+    fn total_access_list_entries(&self) -> usize {
+        self.transactions().iter().filter_map(|tx| tx.access_list()).map(|list| list.len()).sum()
+    }
+
     /// Calculates a heuristic for the in-memory size of the [`BlockBody`].
+    ///
+    /// This is recomputed on every call. See [`SizedBody`] for a wrapper that memoizes it.
     fn size(&self) -> usize;
+
+    /// Returns an estimate of the size of the [`BlockBody`] in the database, without the key and
+    /// page overhead.
+    ///
+    /// This is the length of the [`Compact`](reth_codecs::Compact) encodings of the transactions
+    /// and ommers, as they're stored in the `Transactions` and `BlockOmmers` tables respectively,
+    /// before the optional zstd compression of the transactions. The withdrawals and block body
+    /// indices, stored in separate tables, are not included.
+    #[cfg(feature = "reth-codec")]
+    fn storage_size_hint(&self) -> usize
+    where
+        Self::SignedTransaction: reth_codecs::Compact,
+        Self::Header: reth_codecs::Compact,
+    {
+        use reth_codecs::Compact;
+
+        let mut buf = Vec::new();
+        for tx in self.transactions() {
+            tx.to_compact(&mut buf);
+        }
+        for ommer in self.ommers() {
+            ommer.to_compact(&mut buf);
+        }
+        buf.len()
+    }
+
+    /// Returns the exact length of the RLP encoding of the [`BlockBody`], without encoding it.
+    ///
+    /// As opposed to [`Self::size`], this is the size of the body on the wire.
+    fn rlp_encoded_length(&self) -> usize {
+        alloy_rlp::Encodable::length(self)
+    }
 }
 
 impl<T> BlockBody for T
@@ -106,23 +492,20 @@ where
         + PartialEq
         + Eq
         + Default
-        + serde::Serialize
-        + for<'de> serde::Deserialize<'de>
+        + MaybeSerde
         + alloy_rlp::Encodable
         + alloy_rlp::Decodable,
 {
     type Header = <T::Target as BlockBody>::Header;
     type SignedTransaction = <T::Target as BlockBody>::SignedTransaction;
 
-    fn transactions(&self) -> &Vec<Self::SignedTransaction> {
-        self.deref().transactions()
-    }
+    const SYSTEM_TRANSACTION_TYPE: Option<u8> = <T::Target as BlockBody>::SYSTEM_TRANSACTION_TYPE;
 
-    fn withdrawals(&self) -> Option<&Withdrawals> {
-        self.deref().withdrawals()
+    fn transactions(&self) -> &[Self::SignedTransaction] {
+        self.deref().transactions()
     }
 
-    fn ommers(&self) -> &Vec<Self::Header> {
+    fn ommers(&self) -> &[Self::Header] {
         self.deref().ommers()
     }
 
@@ -138,8 +521,20 @@ where
         self.deref().calculate_ommers_root()
     }
 
-    fn recover_signers(&self) -> Option<Vec<Address>> {
-        self.deref().recover_signers()
+    fn try_recover_signers(&self) -> Result<Vec<Address>, RecoveryError>
+    where
+        Self::SignedTransaction: SignerRecoverable,
+    {
+        self.deref().try_recover_signers()
+    }
+
+    fn recover_transaction_signer(
+        tx: &Self::SignedTransaction,
+    ) -> Result<Address, RecoveryErrorReason>
+    where
+        Self::SignedTransaction: SignerRecoverable,
+    {
+        T::Target::recover_transaction_signer(tx)
     }
 
     fn blob_versioned_hashes_iter(&self) -> impl Iterator<Item = &B256> + '_ {
@@ -150,3 +545,456 @@ where
         self.deref().size()
     }
 }
+
+/// Extension of [`BlockBody`] for block bodies that contain withdrawals, introduced in
+/// [EIP-4895](https://eips.ethereum.org/EIPS/eip-4895).
+pub trait WithdrawalsBlockBody: BlockBody {
+    /// Returns [`Withdrawals`] in the block, if any.
+    fn withdrawals(&self) -> Option<&Withdrawals>;
+
+    /// Returns the number of withdrawals in the block body, or 0 if there are no withdrawals.
+    fn withdrawals_count(&self) -> usize {
+        self.withdrawals().map_or(0, |withdrawals| withdrawals.len())
+    }
+
+    /// Calculate the withdrawals root for the block body, if withdrawals exist. If there are no
+    /// withdrawals, this will return `None`.
+    fn calculate_withdrawals_root(&self) -> Option<B256> {
+        Some(proofs::calculate_withdrawals_root(self.withdrawals()?))
+    }
+}
+
+impl<T> WithdrawalsBlockBody for T
+where
+    T: ops::Deref<Target: WithdrawalsBlockBody> + BlockBody,
+{
+    fn withdrawals(&self) -> Option<&Withdrawals> {
+        self.deref().withdrawals()
+    }
+}
+
+/// A signed transaction whose signer can be recovered from its signature, see
+/// [`BlockBody::recover_transaction_signer`].
+pub trait SignerRecoverable {
+    /// Returns the signature of the transaction.
+    fn signature(&self) -> &Signature;
+
+    /// Returns the hash of the transaction that is signed.
+    fn signature_hash(&self) -> B256;
+}
+
+impl<T: SignableTransaction<Signature>> SignerRecoverable for Signed<T> {
+    fn signature(&self) -> &Signature {
+        self.signature()
+    }
+
+    fn signature_hash(&self) -> B256 {
+        self.signature_hash()
+    }
+}
+
+impl SignerRecoverable for TxEnvelope {
+    fn signature(&self) -> &Signature {
+        match self {
+            Self::Legacy(tx) => tx.signature(),
+            Self::Eip2930(tx) => tx.signature(),
+            Self::Eip1559(tx) => tx.signature(),
+            Self::Eip4844(tx) => tx.signature(),
+            Self::Eip7702(tx) => tx.signature(),
+            _ => unreachable!("unknown transaction type"),
+        }
+    }
+
+    fn signature_hash(&self) -> B256 {
+        self.signature_hash()
+    }
+}
+
+/// A [`BlockBody`] wrapper that memoizes the [`BlockBody::size`] of the inner body.
+///
+/// The size is computed once on construction, and recomputed whenever the inner body is mutated
+/// through [`SizedBody::update`]. Useful when the size is queried often, e.g. for memory
+/// accounting of buffered blocks.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SizedBody<B> {
+    body: B,
+    size: usize,
+}
+
+impl<B: BlockBody> SizedBody<B> {
+    /// Creates a new [`SizedBody`], computing the size of the given body.
+    pub fn new(body: B) -> Self {
+        let size = body.size();
+        Self { body, size }
+    }
+
+    /// Returns a reference to the inner body.
+    pub const fn body(&self) -> &B {
+        &self.body
+    }
+
+    /// Mutates the inner body with the given closure and recomputes its size.
+    pub fn update<R>(&mut self, f: impl FnOnce(&mut B) -> R) -> R {
+        let result = f(&mut self.body);
+        self.size = self.body.size();
+        result
+    }
+
+    /// Consumes the wrapper and returns the inner body.
+    pub fn into_inner(self) -> B {
+        self.body
+    }
+}
+
+impl<B: BlockBody> From<B> for SizedBody<B> {
+    fn from(body: B) -> Self {
+        Self::new(body)
+    }
+}
+
+impl<B: BlockBody> Default for SizedBody<B> {
+    fn default() -> Self {
+        Self::new(B::default())
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<B: serde::Serialize> serde::Serialize for SizedBody<B> {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        self.body.serialize(serializer)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de, B: BlockBody> serde::Deserialize<'de> for SizedBody<B> {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        B::deserialize(deserializer).map(Self::new)
+    }
+}
+
+impl<B: alloy_rlp::Encodable> alloy_rlp::Encodable for SizedBody<B> {
+    fn encode(&self, out: &mut dyn alloy_rlp::BufMut) {
+        self.body.encode(out)
+    }
+
+    fn length(&self) -> usize {
+        self.body.length()
+    }
+}
+
+impl<B: BlockBody> alloy_rlp::Decodable for SizedBody<B> {
+    fn decode(buf: &mut &[u8]) -> alloy_rlp::Result<Self> {
+        B::decode(buf).map(Self::new)
+    }
+}
+
+impl<B: BlockBody> BlockBody for SizedBody<B> {
+    type Header = B::Header;
+    type SignedTransaction = B::SignedTransaction;
+
+    const SYSTEM_TRANSACTION_TYPE: Option<u8> = B::SYSTEM_TRANSACTION_TYPE;
+
+    fn transactions(&self) -> &[Self::SignedTransaction] {
+        self.body.transactions()
+    }
+
+    fn ommers(&self) -> &[Self::Header] {
+        self.body.ommers()
+    }
+
+    fn requests(&self) -> Option<&Requests> {
+        self.body.requests()
+    }
+
+    fn calculate_tx_root(&self) -> B256 {
+        self.body.calculate_tx_root()
+    }
+
+    fn calculate_ommers_root(&self) -> B256 {
+        self.body.calculate_ommers_root()
+    }
+
+    fn recover_transaction_signer(
+        tx: &Self::SignedTransaction,
+    ) -> Result<Address, RecoveryErrorReason>
+    where
+        Self::SignedTransaction: SignerRecoverable,
+    {
+        B::recover_transaction_signer(tx)
+    }
+
+    fn blob_versioned_hashes_iter(&self) -> impl Iterator<Item = &B256> + '_ {
+        self.body.blob_versioned_hashes_iter()
+    }
+
+    fn size(&self) -> usize {
+        self.size
+    }
+}
+
+impl<B: WithdrawalsBlockBody> WithdrawalsBlockBody for SizedBody<B> {
+    fn withdrawals(&self) -> Option<&Withdrawals> {
+        self.body.withdrawals()
+    }
+}
+
+/// The transactions removed and added between two block bodies. See [`BlockBody::diff`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BodyDiff<'a, T> {
+    /// The transactions in the old block body that are not in the new one.
+    pub removed: Vec<&'a T>,
+    /// The transactions in the new block body that are not in the old one.
+    pub added: Vec<&'a T>,
+}
+
+impl<T> BodyDiff<'_, T> {
+    /// Returns `true` if both block bodies contain the same transactions.
+    pub fn is_empty(&self) -> bool {
+        self.removed.is_empty() && self.added.is_empty()
+    }
+}
+
+/// Error returned when a [`BlockBody`] is invalid.
+#[derive(Debug, Clone, PartialEq, Eq, derive_more::Display)]
+pub enum BlockBodyError {
+    /// The transaction root of the block body doesn't match the expected one.
+    #[display("mismatched block body transaction root: {_0}")]
+    TransactionRootMismatch(GotExpectedBoxed<B256>),
+    /// The block body has more blobs than allowed.
+    #[display("too many blobs in block body: {count} > {max}")]
+    TooManyBlobs {
+        /// The number of blobs in the block body.
+        count: usize,
+        /// The maximum number of blobs allowed.
+        max: usize,
+    },
+}
+
+impl core::error::Error for BlockBodyError {}
+
+/// Error returned when the signer of a transaction in a [`BlockBody`] couldn't be recovered.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, derive_more::Display)]
+#[display("failed to recover signer of transaction at index {index}: {reason}")]
+pub struct RecoveryError {
+    /// The index of the transaction in the block body.
+    pub index: usize,
+    /// The reason of the failure.
+    pub reason: RecoveryErrorReason,
+}
+
+impl core::error::Error for RecoveryError {}
+
+/// The reason of a [`RecoveryError`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, derive_more::Display)]
+pub enum RecoveryErrorReason {
+    /// The signature is malformed, e.g. its `s` value is higher than `secp256k1n / 2` for a
+    /// transaction after EIP-2.
+    #[display("invalid signature")]
+    InvalidSignature,
+    /// The public key couldn't be recovered from a well-formed signature.
+    #[display("public key recovery failed")]
+    PublicKeyRecovery,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloy_consensus::{Header, TxEip7702, TxLegacy};
+    use alloy_primitives::{uint, U256};
+
+    use reth_trie_common::root::ordered_trie_root_with_encoder;
+
+    use crate::{constants::EMPTY_OMMER_ROOT_HASH, public_key_to_address, sign_message};
+
+    /// The order of the secp256k1 curve.
+    const SECP256K1N: U256 =
+        uint!(0xFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFEBAAEDCE6AF48A03BBFD25E8CD0364141_U256);
+
+    /// Minimal [`BlockBody`] implementation over [`TxEnvelope`]s.
+    #[derive(
+        Debug, Clone, PartialEq, Eq, Default, alloy_rlp::RlpEncodable, alloy_rlp::RlpDecodable,
+    )]
+    #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+    struct TestBody {
+        transactions: Vec<TxEnvelope>,
+        ommers: Vec<Header>,
+    }
+
+    impl BlockBody for TestBody {
+        type SignedTransaction = TxEnvelope;
+        type Header = Header;
+
+        fn transactions(&self) -> &[Self::SignedTransaction] {
+            &self.transactions
+        }
+
+        fn ommers(&self) -> &[Self::Header] {
+            &self.ommers
+        }
+
+        fn requests(&self) -> Option<&Requests> {
+            None
+        }
+
+        fn calculate_tx_root(&self) -> B256 {
+            ordered_trie_root_with_encoder(&self.transactions, |tx, buf| tx.encode_2718(buf))
+        }
+
+        fn calculate_ommers_root(&self) -> B256 {
+            proofs::calculate_ommers_root(&self.ommers)
+        }
+
+        fn blob_versioned_hashes_iter(&self) -> impl Iterator<Item = &B256> + '_ {
+            self.transactions.iter().filter_map(|tx| tx.blob_versioned_hashes()).flatten()
+        }
+
+        fn size(&self) -> usize {
+            core::mem::size_of::<Self>() +
+                self.transactions.capacity() * core::mem::size_of::<TxEnvelope>() +
+                self.ommers.iter().map(Header::size).sum::<usize>()
+        }
+    }
+
+    fn legacy_tx(nonce: u64) -> TxEnvelope {
+        TxLegacy { nonce, ..Default::default() }.into_signed(Signature::test_signature()).into()
+    }
+
+    fn legacy_tx_with_gas(gas_limit: u64, gas_price: u128) -> TxEnvelope {
+        TxLegacy { gas_limit, gas_price, ..Default::default() }
+            .into_signed(Signature::test_signature())
+            .into()
+    }
+
+    fn eip7702_tx(nonce: u64) -> TxEnvelope {
+        TxEip7702 { nonce, ..Default::default() }.into_signed(Signature::test_signature()).into()
+    }
+
+    /// Returns a legacy transaction signed by a random key, and the address of the key.
+    fn signed_legacy_tx(nonce: u64) -> (TxEnvelope, Address) {
+        let key_pair = secp256k1::Keypair::new(secp256k1::SECP256K1, &mut rand::thread_rng());
+        let tx = TxLegacy { nonce, ..Default::default() };
+        let signature =
+            sign_message(B256::from_slice(&key_pair.secret_bytes()[..]), tx.signature_hash())
+                .unwrap();
+        (tx.into_signed(signature).into(), public_key_to_address(key_pair.public_key()))
+    }
+
+    fn body(nonces: impl IntoIterator<Item = u64>) -> TestBody {
+        TestBody { transactions: nonces.into_iter().map(legacy_tx).collect(), ..Default::default() }
+    }
+
+    #[test]
+    fn diff_identical() {
+        let (old, new) = (body(0..3), body(0..3));
+
+        let diff = old.diff(&new);
+        assert!(diff.is_empty());
+    }
+
+    #[test]
+    fn diff_disjoint() {
+        let (old, new) = (body(0..2), body(2..4));
+
+        let diff = old.diff(&new);
+        assert_eq!(diff.removed, old.transactions.iter().collect::<Vec<_>>());
+        assert_eq!(diff.added, new.transactions.iter().collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn diff_partially_overlapping() {
+        let (old, new) = (body([0, 1, 2]), body([3, 1, 4]));
+
+        let diff = old.diff(&new);
+        assert_eq!(diff.removed, vec![&old.transactions[0], &old.transactions[2]]);
+        assert_eq!(diff.added, vec![&new.transactions[0], &new.transactions[2]]);
+    }
+
+    #[test]
+    fn eip7702_transactions() {
+        let body = TestBody {
+            transactions: vec![legacy_tx(0), eip7702_tx(1), legacy_tx(2), eip7702_tx(3)],
+            ..Default::default()
+        };
+
+        assert_eq!(
+            body.eip7702_transactions_iter().collect::<Vec<_>>(),
+            vec![&body.transactions[1], &body.transactions[3]]
+        );
+        assert!(body.has_eip7702_transactions());
+        assert!(TestBody::default().eip7702_transactions().is_empty());
+    }
+
+    #[test]
+    fn estimated_coinbase_tips() {
+        let body = TestBody {
+            transactions: vec![legacy_tx_with_gas(100_000, 3), legacy_tx_with_gas(300_000, 2)],
+            ..Default::default()
+        };
+
+        // The gas used is attributed to the transactions proportionally to their gas limits, i.e.
+        // 25_000 to the first one and 75_000 to the second one, regardless of their order
+        let header = Header { gas_used: 100_000, base_fee_per_gas: Some(1), ..Default::default() };
+        assert_eq!(body.estimated_coinbase_tips(&header), 2 * 25_000 + 75_000);
+
+        // All transactions used their gas limits
+        let header = Header { gas_used: 400_000, base_fee_per_gas: Some(1), ..Default::default() };
+        assert_eq!(body.estimated_coinbase_tips(&header), 2 * 100_000 + 300_000);
+    }
+
+    #[test]
+    fn validate_tx_root() {
+        let body = body(0..3);
+        let expected = body.calculate_tx_root();
+        assert_eq!(body.validate_tx_root(expected), Ok(()));
+
+        // The root commits to the order of the transactions
+        let reversed = TestBody {
+            transactions: body.transactions.iter().rev().cloned().collect(),
+            ..Default::default()
+        };
+        assert_eq!(
+            reversed.validate_tx_root(expected),
+            Err(BlockBodyError::TransactionRootMismatch(
+                GotExpected { got: reversed.calculate_tx_root(), expected }.into()
+            ))
+        );
+    }
+
+    #[test]
+    fn ommers_root() {
+        assert_eq!(TestBody::default().calculate_ommers_root(), EMPTY_OMMER_ROOT_HASH);
+
+        let body = TestBody { ommers: vec![Header::default()], ..Default::default() };
+        assert_eq!(body.ommers_count(), 1);
+        assert_ne!(body.calculate_ommers_root(), EMPTY_OMMER_ROOT_HASH);
+    }
+
+    #[test]
+    fn recover_signers() {
+        let (transactions, senders): (Vec<_>, Vec<_>) = (0..3).map(signed_legacy_tx).unzip();
+        let body = TestBody { transactions, ..Default::default() };
+
+        assert_eq!(body.try_recover_signers(), Ok(senders.clone()));
+        assert_eq!(body.recover_signers(), Some(senders));
+    }
+
+    #[test]
+    fn recover_signers_high_s() {
+        let (transactions, _): (Vec<_>, Vec<_>) = (0..3).map(signed_legacy_tx).unzip();
+        let mut body = TestBody { transactions, ..Default::default() };
+
+        // Flip the signature of the second transaction to its high `s` counterpart, which is
+        // valid for secp256k1 but rejected after EIP-2
+        let TxEnvelope::Legacy(tx) = &body.transactions[1] else { unreachable!() };
+        let signature = tx.signature();
+        let signature =
+            Signature::new(signature.r(), SECP256K1N - signature.s(), signature.v().inverted());
+        body.transactions[1] = tx.tx().clone().into_signed(signature).into();
+
+        assert_eq!(
+            body.try_recover_signers(),
+            Err(RecoveryError { index: 1, reason: RecoveryErrorReason::InvalidSignature })
+        );
+        assert_eq!(body.recover_signers(), None);
+    }
+}
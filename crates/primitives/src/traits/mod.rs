@@ -2,6 +2,28 @@
 
 pub mod block;
 
-pub use block::{body::BlockBody, Block};
+pub use block::{
+    body::{
+        BlockBody, BlockBodyError, BodyDiff, RecoveryError, RecoveryErrorReason, SignerRecoverable,
+        SizedBody, WithdrawalsBlockBody,
+    },
+    Block,
+};
 
 pub use alloy_consensus::BlockHeader;
+
+/// Helper trait that requires [`serde`] (de)serialization if the `serde` feature is enabled, and
+/// is implemented for all types otherwise.
+#[cfg(feature = "serde")]
+pub trait MaybeSerde: serde::Serialize + for<'de> serde::Deserialize<'de> {}
+
+#[cfg(feature = "serde")]
+impl<T> MaybeSerde for T where T: serde::Serialize + for<'de> serde::Deserialize<'de> {}
+
+/// Helper trait that requires [`serde`] (de)serialization if the `serde` feature is enabled, and
+/// is implemented for all types otherwise.
+#[cfg(not(feature = "serde"))]
+pub trait MaybeSerde {}
+
+#[cfg(not(feature = "serde"))]
+impl<T> MaybeSerde for T {}
@@ -13,7 +13,9 @@ use alloy_primitives::{Bytes, TxHash};
 use alloy_rlp::{Decodable, Encodable, Error as RlpError, Header};
 use core::mem;
 use derive_more::{AsRef, Deref};
+#[cfg(feature = "rayon")]
 use once_cell::sync::Lazy;
+#[cfg(feature = "rayon")]
 use rayon::prelude::{IntoParallelIterator, ParallelIterator};
 use serde::{Deserialize, Serialize};
 use signature::{decode_with_eip155_chain_id, with_eip155_parity};
@@ -72,6 +74,12 @@ pub type TxHashOrNumber = BlockHashOrNumber;
 
 // Expected number of transactions where we can expect a speed-up by recovering the senders in
 // parallel.
+//
+// Recovering a single sender takes tens of microseconds, while handing a task to the rayon thread
+// pool only costs a few, so parallel recovery pays off from a handful of transactions as soon as
+// there's more than one thread. With a single thread, it's pure overhead. See the
+// `recover_signers` benchmark, comparing both for blocks of 2 to 1500 transactions.
+#[cfg(feature = "rayon")]
 pub(crate) static PARALLEL_SENDER_RECOVERY_THRESHOLD: Lazy<usize> =
     Lazy::new(|| match rayon::current_num_threads() {
         0..=1 => usize::MAX,
@@ -79,6 +87,53 @@ pub(crate) static PARALLEL_SENDER_RECOVERY_THRESHOLD: Lazy<usize> =
         _ => 5,
     });
 
+/// A list of transactions to recover the signers of, see [`TransactionSigned::recover_signers`].
+///
+/// If the `rayon` feature is enabled, the list has to be a parallel iterator too, so that the
+/// signers of large lists can be recovered in parallel.
+#[cfg(feature = "rayon")]
+pub trait IntoTransactionsIter<'a, T: 'a>:
+    IntoParallelIterator<Item = &'a T> + IntoIterator<Item = &'a T>
+{
+}
+
+#[cfg(feature = "rayon")]
+impl<'a, T: 'a, I> IntoTransactionsIter<'a, T> for I where
+    I: IntoParallelIterator<Item = &'a T> + IntoIterator<Item = &'a T>
+{
+}
+
+/// A list of transactions to recover the signers of, see [`TransactionSigned::recover_signers`].
+///
+/// If the `rayon` feature is enabled, the list has to be a parallel iterator too, so that the
+/// signers of large lists can be recovered in parallel.
+#[cfg(not(feature = "rayon"))]
+pub trait IntoTransactionsIter<'a, T: 'a>: IntoIterator<Item = &'a T> {}
+
+#[cfg(not(feature = "rayon"))]
+impl<'a, T: 'a, I> IntoTransactionsIter<'a, T> for I where I: IntoIterator<Item = &'a T> {}
+
+/// Recovers the signers of the transactions with the given function, in parallel if there are at
+/// least [`PARALLEL_SENDER_RECOVERY_THRESHOLD`] transactions and the `rayon` feature is enabled.
+fn recover_signers_with<'a, Tx, T>(
+    txes: T,
+    num_txes: usize,
+    recover: impl Fn(&'a Tx) -> Option<Address> + Send + Sync,
+) -> Option<Vec<Address>>
+where
+    Tx: 'a,
+    T: IntoTransactionsIter<'a, Tx>,
+{
+    #[cfg(feature = "rayon")]
+    if num_txes >= *PARALLEL_SENDER_RECOVERY_THRESHOLD {
+        return txes.into_par_iter().map(recover).collect()
+    }
+    #[cfg(not(feature = "rayon"))]
+    let _ = num_txes;
+
+    txes.into_iter().map(recover).collect()
+}
+
 /// A raw transaction.
 ///
 /// Transaction types were introduced in [EIP-2718](https://eips.ethereum.org/EIPS/eip-2718).
@@ -911,13 +966,9 @@ impl TransactionSignedNoHash {
     /// [`Self::recover_signer`].
     pub fn recover_signers<'a, T>(txes: T, num_txes: usize) -> Option<Vec<Address>>
     where
-        T: IntoParallelIterator<Item = &'a Self> + IntoIterator<Item = &'a Self> + Send,
+        T: IntoTransactionsIter<'a, Self> + Send,
     {
-        if num_txes < *PARALLEL_SENDER_RECOVERY_THRESHOLD {
-            txes.into_iter().map(|tx| tx.recover_signer()).collect()
-        } else {
-            txes.into_par_iter().map(|tx| tx.recover_signer()).collect()
-        }
+        recover_signers_with(txes, num_txes, Self::recover_signer)
     }
 }
 
@@ -1122,13 +1173,9 @@ impl TransactionSigned {
     /// [`Self::recover_signer`].
     pub fn recover_signers<'a, T>(txes: T, num_txes: usize) -> Option<Vec<Address>>
     where
-        T: IntoParallelIterator<Item = &'a Self> + IntoIterator<Item = &'a Self> + Send,
+        T: IntoTransactionsIter<'a, Self> + Send,
     {
-        if num_txes < *PARALLEL_SENDER_RECOVERY_THRESHOLD {
-            txes.into_iter().map(|tx| tx.recover_signer()).collect()
-        } else {
-            txes.into_par_iter().map(|tx| tx.recover_signer()).collect()
-        }
+        recover_signers_with(txes, num_txes, Self::recover_signer)
     }
 
     /// Recovers a list of signers from a transaction list iterator _without ensuring that the
@@ -1138,13 +1185,9 @@ impl TransactionSigned {
     /// [`Self::recover_signer_unchecked`].
     pub fn recover_signers_unchecked<'a, T>(txes: T, num_txes: usize) -> Option<Vec<Address>>
     where
-        T: IntoParallelIterator<Item = &'a Self> + IntoIterator<Item = &'a Self>,
+        T: IntoTransactionsIter<'a, Self>,
     {
-        if num_txes < *PARALLEL_SENDER_RECOVERY_THRESHOLD {
-            txes.into_iter().map(|tx| tx.recover_signer_unchecked()).collect()
-        } else {
-            txes.into_par_iter().map(|tx| tx.recover_signer_unchecked()).collect()
-        }
+        recover_signers_with(txes, num_txes, Self::recover_signer_unchecked)
     }
 
     /// Returns the [`TransactionSignedEcRecovered`] transaction with the given sender.
@@ -2057,7 +2100,7 @@ mod tests {
         assert_eq!(data.as_slice(), b.as_slice());
     }
 
-    #[cfg(feature = "secp256k1")]
+    #[cfg(all(feature = "secp256k1", feature = "rayon"))]
     proptest::proptest! {
         #![proptest_config(proptest::prelude::ProptestConfig::with_cases(1))]
 
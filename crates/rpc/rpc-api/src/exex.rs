@@ -0,0 +1,11 @@
+use jsonrpsee::{core::RpcResult, proc_macros::rpc};
+use reth_exex_types::ExExStatus;
+
+/// Reth API namespace for `ExEx`-specific methods
+#[cfg_attr(not(feature = "client"), rpc(server, namespace = "reth"))]
+#[cfg_attr(feature = "client", rpc(server, client, namespace = "reth"))]
+pub trait ExExApi {
+    /// Returns the statuses of all `ExEx`'s registered in the node
+    #[method(name = "exExStatus")]
+    fn exex_status(&self) -> RpcResult<Vec<ExExStatus>>;
+}
@@ -18,6 +18,7 @@ mod admin;
 mod anvil;
 mod debug;
 mod engine;
+mod exex;
 mod ganache;
 mod hardhat;
 mod mev;
@@ -39,6 +40,7 @@ pub mod servers {
         admin::AdminApiServer,
         debug::DebugApiServer,
         engine::{EngineApiServer, EngineEthApiServer},
+        exex::ExExApiServer,
         mev::{MevFullApiServer, MevSimApiServer},
         net::NetApiServer,
         otterscan::OtterscanServer,
@@ -67,6 +69,7 @@ pub mod clients {
         anvil::AnvilApiClient,
         debug::DebugApiClient,
         engine::{EngineApiClient, EngineEthApiClient},
+        exex::ExExApiClient,
         ganache::GanacheApiClient,
         hardhat::HardhatApiClient,
         mev::{MevFullApiClient, MevSimApiClient},
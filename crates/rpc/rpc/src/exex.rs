@@ -0,0 +1,27 @@
+use jsonrpsee::core::RpcResult;
+use reth_exex_types::ExExStatus;
+use reth_rpc_api::ExExApiServer;
+use tokio::sync::watch;
+
+/// `reth` API implementation for `ExEx`-specific methods.
+///
+/// This type provides the functionality for handling `ExEx` related requests.
+#[derive(Debug, Clone)]
+pub struct ExExApi {
+    /// The statuses of all `ExEx`'s, updated by the `ExEx` manager.
+    statuses: watch::Receiver<Vec<ExExStatus>>,
+}
+
+impl ExExApi {
+    /// Creates a new instance of `ExExApi`.
+    pub const fn new(statuses: watch::Receiver<Vec<ExExStatus>>) -> Self {
+        Self { statuses }
+    }
+}
+
+impl ExExApiServer for ExExApi {
+    /// Handler for `reth_exExStatus`
+    fn exex_status(&self) -> RpcResult<Vec<ExExStatus>> {
+        Ok(self.statuses.borrow().clone())
+    }
+}
@@ -36,6 +36,7 @@ mod admin;
 mod debug;
 mod engine;
 pub mod eth;
+mod exex;
 mod net;
 mod otterscan;
 mod reth;
@@ -47,6 +48,7 @@ pub use admin::AdminApi;
 pub use debug::DebugApi;
 pub use engine::{EngineApi, EngineEthApi};
 pub use eth::{EthApi, EthBundle, EthFilter, EthPubSub};
+pub use exex::ExExApi;
 pub use net::NetApi;
 pub use otterscan::OtterscanApi;
 pub use reth::RethApi;